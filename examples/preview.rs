@@ -13,6 +13,8 @@ fn main() {
 	let palette_type = match args[1].to_lowercase().as_ref() {
 		"pastel" => PaletteType::Pastel,
 		"dark" => PaletteType::Dark,
+		"oklch" => PaletteType::Oklch,
+		"monochrome" => PaletteType::Monochrome,
 		_ => PaletteType::Random
 	};
 
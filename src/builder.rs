@@ -0,0 +1,370 @@
+use core::ops::RangeInclusive;
+
+use rand::Rng;
+
+use crate::math;
+use crate::seed::seed_hue;
+use crate::{Color, ColorPalette, Hsv, HsvPalette, Hue, HueWalk, PaletteStrategy, PaletteType, Saturation, Spread, Value};
+
+/// The ranges a palette keeps the hue, saturation and value of its colors in.
+///
+/// A hue range may wrap around the color wheel, `330.0..=30.0` covers the reds on both sides of 0°.
+/// Hues are wrapped into the range, so a palette stepping by 25° through `0.0..=60.0` generates hues
+/// 25°, 50°, 15°, 40° and so on. Saturation and value are clamped to their ranges, whose bounds may come in
+/// either order. A range with a `NaN` bound (or an infinite hue bound) does not restrict anything.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ranges {
+    pub hue: RangeInclusive<Hue>,
+    pub saturation: RangeInclusive<Saturation>,
+    pub value: RangeInclusive<Value>,
+}
+
+impl Default for Ranges {
+    /// The whole color wheel with any saturation and value
+    fn default() -> Self {
+        Ranges {
+            hue: 0.0..=360.0,
+            saturation: 0.0..=1.0,
+            value: 0.0..=1.0,
+        }
+    }
+}
+
+/// The bounds of a saturation or value range in order, so clamping to them can never fail.
+/// A `NaN` bound falls back to the full range
+fn unit_bounds(range: &RangeInclusive<f32>) -> (f32, f32) {
+    let (start, end) = (*range.start(), *range.end());
+    if start.is_nan() || end.is_nan() {
+        return (0.0, 1.0);
+    }
+    (start.min(end), start.max(end))
+}
+
+impl Ranges {
+    /// Move `hsv` into these ranges. Saturation and value ranges may have their bounds in any order
+    pub(crate) fn apply(&self, hsv: Hsv) -> Hsv {
+        let (start, end) = (*self.hue.start(), *self.hue.end());
+        let mut hue = hsv.hue();
+        // A hue range which is not finite or spans at least 360° covers the whole color wheel
+        if start.is_finite() && end.is_finite() {
+            let mut width = end - start;
+            if width < 0.0 {
+                width += 360.0;
+            }
+            if width < 360.0 {
+                // Wrap the offset from the start of the range, so hues already within it stay where they are
+                hue = if width > 0.0 { start + math::rem_euclid(hue - start, width) } else { start };
+            }
+        }
+
+        let (min, max) = unit_bounds(&self.saturation);
+        let saturation = hsv.saturation().clamp(min, max);
+        let (min, max) = unit_bounds(&self.value);
+        let value = hsv.value().clamp(min, max);
+        Hsv::new(hue, saturation, value)
+    }
+}
+
+/// Configures a `ColorPalette` or `HsvPalette` in more detail than their constructors allow.
+/// Create one with `ColorPalette::builder` or `HsvPalette::builder`.
+///
+/// ```rust
+/// use colourado_iter::{ColorPalette, PaletteType};
+///
+/// // Warm and fairly saturated colors, 10° apart
+/// let palette = ColorPalette::builder()
+///     .with_palette_type(PaletteType::Pastel)
+///     .with_hue(0.0..=60.0)
+///     .with_saturation(0.5..=0.8)
+///     .with_divergence(10.0)
+///     .build(&mut rand::thread_rng());
+/// let colors: Vec<_> = palette.take(6).collect();
+/// ```
+///
+/// Every range which is not set falls back to `PaletteStrategy::ranges` of the palette type.
+pub struct PaletteBuilder<S = PaletteType> {
+    strategy: S,
+    spread: Spread,
+    divergence: Option<f32>,
+    jitter: Option<f32>,
+    start_hue: Option<Hue>,
+    walk: HueWalk,
+    hue: Option<RangeInclusive<Hue>>,
+    saturation: Option<RangeInclusive<Saturation>>,
+    value: Option<RangeInclusive<Value>>,
+    first: Option<(Hsv, Color)>,
+}
+
+impl PaletteBuilder {
+    /// A builder for a `PaletteType::Random` palette with `Spread::Wide`
+    pub fn new() -> Self {
+        PaletteBuilder {
+            strategy: PaletteType::Random,
+            spread: Spread::Wide,
+            divergence: None,
+            jitter: None,
+            start_hue: None,
+            walk: HueWalk::Accumulate,
+            hue: None,
+            saturation: None,
+            value: None,
+            first: None,
+        }
+    }
+}
+
+impl Default for PaletteBuilder {
+    fn default() -> Self {
+        PaletteBuilder::new()
+    }
+}
+
+impl<S: PaletteStrategy> PaletteBuilder<S> {
+    /// Use one of the built-in palettes
+    pub fn with_palette_type(self, palette_type: PaletteType) -> PaletteBuilder<PaletteType> {
+        self.with_strategy(palette_type)
+    }
+
+    /// Use your own `PaletteStrategy`
+    pub fn with_strategy<N: PaletteStrategy>(self, strategy: N) -> PaletteBuilder<N> {
+        PaletteBuilder {
+            strategy,
+            spread: self.spread,
+            divergence: self.divergence,
+            jitter: self.jitter,
+            start_hue: self.start_hue,
+            walk: self.walk,
+            hue: self.hue,
+            saturation: self.saturation,
+            value: self.value,
+            first: self.first,
+        }
+    }
+
+    /// Set the hue step and jitter from a `Spread`. `with_divergence` and `with_jitter` override either one
+    pub fn with_spread(mut self, spread: Spread) -> Self {
+        self.spread = spread;
+        self
+    }
+
+    /// Set the hue step in degrees between consecutive colors
+    pub fn with_divergence(mut self, divergence: f32) -> Self {
+        self.divergence = Some(divergence);
+        self
+    }
+
+    /// Scale the irregularity added to every hue step, where 1.0 is the default amount and 0.0 turns it off
+    pub fn with_jitter(mut self, jitter: f32) -> Self {
+        self.jitter = Some(jitter);
+        self
+    }
+
+    /// Start walking the color wheel at `hue` instead of a random hue
+    pub fn with_start_hue(mut self, hue: Hue) -> Self {
+        self.start_hue = Some(hue);
+        self
+    }
+
+    /// Derive the starting hue from `seed`, so the palette is the same on every platform and release.
+    /// See `ColorPalette::from_seed`
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.start_hue = Some(seed_hue(seed));
+        self
+    }
+
+    /// Choose how the palette steps from one hue to the next. `HueWalk::Indexed` lets the palette
+    /// seek to any color directly
+    pub fn with_walk(mut self, walk: HueWalk) -> Self {
+        self.walk = walk;
+        self
+    }
+
+    /// Emit exactly `color` first and walk the color wheel from its hue afterwards.
+    /// This replaces any starting hue
+    pub fn with_start_color(mut self, color: Color) -> Self {
+        self.first = Some((color.to_hsv(), color));
+        self
+    }
+
+    /// Emit exactly `hsv` first and walk the color wheel from its hue afterwards.
+    /// This replaces any starting hue
+    pub fn with_start_hsv(mut self, hsv: Hsv) -> Self {
+        self.first = Some((hsv, Color::from(hsv)));
+        self
+    }
+
+    /// Only generate hues within `hue`, see `Ranges`
+    pub fn with_hue(mut self, hue: RangeInclusive<Hue>) -> Self {
+        self.hue = Some(hue);
+        self
+    }
+
+    /// Clamp the saturation of the generated colors to `saturation` (0.0 - 1.0)
+    pub fn with_saturation(mut self, saturation: RangeInclusive<Saturation>) -> Self {
+        self.saturation = Some(saturation);
+        self
+    }
+
+    /// Clamp the value of the generated colors to `value` (0.0 - 1.0)
+    pub fn with_value(mut self, value: RangeInclusive<Value>) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// Build the palette. `rng` picks the starting hue unless one was set
+    pub fn build<T: Rng>(self, rng: &mut T) -> ColorPalette<S> {
+        ColorPalette(self.build_hsv(rng))
+    }
+
+    /// Build a palette generating `Hsv` colors. `rng` picks the starting hue unless one was set
+    pub fn build_hsv<T: Rng>(self, rng: &mut T) -> HsvPalette<S> {
+        let hue = match (self.first, self.start_hue) {
+            (Some((hsv, _)), _) => hsv.hue(),
+            (None, Some(hue)) => hue,
+            (None, None) => rng.gen_range(0.0..360.0),
+        };
+        self.build_at(hue)
+    }
+
+    /// Build a palette which starts at the starting color or hue, without needing a random starting hue
+    pub(crate) fn build_from_start(self) -> ColorPalette<S> {
+        let hue = match (self.first, self.start_hue) {
+            (Some((hsv, _)), _) => hsv.hue(),
+            (None, hue) => hue.unwrap_or(0.0),
+        };
+        ColorPalette(self.build_at(hue))
+    }
+
+    fn build_at(self, hue: Hue) -> HsvPalette<S> {
+        let (divergence, jitter) = self.spread.divergence_and_jitter();
+        let defaults = self.strategy.ranges();
+        let ranges = Ranges {
+            hue: self.hue.unwrap_or(defaults.hue),
+            saturation: self.saturation.unwrap_or(defaults.saturation),
+            value: self.value.unwrap_or(defaults.value),
+        };
+
+        HsvPalette {
+            iteration: 0,
+            base_divergence: self.divergence.unwrap_or(divergence),
+            jitter: self.jitter.unwrap_or(jitter),
+            palette_type: self.strategy,
+            hue,
+            start_hue: hue,
+            walk: self.walk,
+            ranges,
+            first: self.first,
+        }
+    }
+}
+
+impl ColorPalette {
+    /// Configure a palette step by step, see `PaletteBuilder`
+    pub fn builder() -> PaletteBuilder {
+        PaletteBuilder::new()
+    }
+}
+
+impl HsvPalette {
+    /// Configure a palette step by step, see `PaletteBuilder`
+    pub fn builder() -> PaletteBuilder {
+        PaletteBuilder::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Ranges;
+    use crate::{Color, ColorPalette, Harmony, Hsv, HsvPalette, HueWalk, PaletteType, Spread};
+
+    #[test]
+    fn respects_ranges() {
+        let palette = HsvPalette::builder()
+            .with_hue(330.0..=30.0)
+            .with_saturation(0.9..=0.6)
+            .with_value(0.3..=0.5)
+            .with_divergence(7.0)
+            .build_hsv(&mut rand::thread_rng());
+
+        for hsv in palette.take(50) {
+            assert!(hsv.hue() >= 330.0 || hsv.hue() <= 30.0);
+            assert!((0.6..=0.9).contains(&hsv.saturation()));
+            assert!((0.3..=0.5).contains(&hsv.value()));
+        }
+    }
+
+    #[test]
+    fn tolerates_any_bounds() {
+        let hsv = Hsv::new(200.0, 0.3, 0.95);
+        let inverted = Ranges {
+            saturation: 0.9..=0.6,
+            value: 0.5..=0.1,
+            ..Ranges::default()
+        };
+        assert_eq!(inverted.apply(hsv), Hsv::new(200.0, 0.6, 0.5));
+
+        let nan = Ranges {
+            hue: f32::NAN..=30.0,
+            saturation: f32::NAN..=0.5,
+            value: 0.2..=f32::NAN,
+        };
+        assert_eq!(nan.apply(hsv), hsv);
+    }
+
+    #[test]
+    fn keeps_hues_within_range() {
+        let hsv = Hsv::new(200.0, 0.5, 0.5);
+        let whole_wheel = Ranges {
+            hue: -30.0..=330.0,
+            ..Ranges::default()
+        };
+        assert_eq!(whole_wheel.apply(hsv), hsv);
+
+        let hue = ColorPalette::builder()
+            .with_start_hue(120.0)
+            .with_hue(100.0..=160.0)
+            .with_walk(HueWalk::Indexed)
+            .build(&mut rand::thread_rng())
+            .into_inner()
+            .get()
+            .hue();
+        assert_eq!(hue, 120.0);
+    }
+
+    #[test]
+    fn steps_from_start_hue() {
+        let hues = |walk| -> Vec<_> {
+            ColorPalette::builder()
+                .with_palette_type(PaletteType::Pastel)
+                .with_start_hue(100.0)
+                .with_divergence(10.0)
+                .with_jitter(0.0)
+                .with_walk(walk)
+                .build(&mut rand::thread_rng())
+                .into_inner()
+                .take(3)
+                .map(|hsv| hsv.hue())
+                .collect()
+        };
+        assert_eq!(hues(HueWalk::Accumulate), [110.0, 120.0, 130.0]);
+        assert_eq!(hues(HueWalk::Indexed), [100.0, 110.0, 120.0]);
+    }
+
+    #[test]
+    fn starts_with_color() {
+        let brand = Color::from_hex("#1E90FF").unwrap();
+        let colors: Vec<_> = ColorPalette::from_color(PaletteType::Harmony(Harmony::Complementary), brand, Spread::Wide)
+            .take(2)
+            .collect();
+        assert_eq!(colors[0], brand);
+        assert!((colors[1].to_hsv().hue() - (brand.to_hsv().hue() + 180.0) % 360.0).abs() < 0.001);
+
+        let palette = HsvPalette::builder()
+            .with_start_hsv(brand.to_hsv())
+            .with_value(0.0..=0.5)
+            .build_hsv(&mut rand::thread_rng());
+        assert_eq!(palette.get(), brand.to_hsv());
+        assert_eq!(palette.skip(1).take(20).filter(|hsv| hsv.value() > 0.5).count(), 0);
+    }
+}
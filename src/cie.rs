@@ -0,0 +1,375 @@
+use crate::{Color, Hue};
+use crate::precision::single::normalize_hue;
+use crate::math;
+
+/// Reference white of the D65 illuminant, which sRGB is defined against
+const WHITE: [f32; 3] = [0.95047, 1.0, 1.08883];
+
+/// CIE constants for the L*a*b* transfer function, see http://www.brucelindbloom.com/LContinuity.html
+const EPSILON: f32 = 216.0 / 24389.0;
+const KAPPA: f32 = 24389.0 / 27.0;
+
+/// Decode a single sRGB gamma encoded component (as stored in `Color`) to linear light
+pub fn srgb_to_linear(x: f32) -> f32 {
+    if x <= 0.04045 {
+        x / 12.92
+    } else {
+        math::powf((x + 0.055) / 1.055, 2.4)
+    }
+}
+
+/// Encode a single linear light component with the sRGB gamma curve
+pub fn linear_to_srgb(x: f32) -> f32 {
+    if x <= 0.0031308 {
+        x * 12.92
+    } else {
+        1.055 * math::powf(x, 1.0 / 2.4) - 0.055
+    }
+}
+
+/// An RGB color with linear light components, meaning the sRGB gamma curve has been removed.
+/// This is the space in which light mixes physically, as opposed to the gamma encoded `Color`.
+/// Components are not clamped, so colors outside of the sRGB gamut can be represented.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LinearRgb {
+    red: f32,
+    green: f32,
+    blue: f32,
+}
+
+impl LinearRgb {
+    pub fn new(red: f32, green: f32, blue: f32) -> Self {
+        LinearRgb { red, green, blue }
+    }
+
+    pub fn red(&self) -> f32 {
+        self.red
+    }
+
+    pub fn green(&self) -> f32 {
+        self.green
+    }
+
+    pub fn blue(&self) -> f32 {
+        self.blue
+    }
+
+    /// Convert to an array of 3 floats
+    pub fn to_array(&self) -> [f32; 3] {
+        [self.red, self.green, self.blue]
+    }
+
+    /// Encode with the sRGB gamma curve. Components outside of the gamut are clamped
+    pub fn to_color(&self) -> Color {
+        Color::from_rgb_f32(linear_to_srgb(self.red), linear_to_srgb(self.green), linear_to_srgb(self.blue))
+    }
+
+    /// Convert to CIE XYZ
+    pub fn to_xyz(&self) -> Xyz {
+        let (r, g, b) = (self.red, self.green, self.blue);
+        Xyz::new(
+            0.4124564 * r + 0.3575761 * g + 0.1804375 * b,
+            0.2126729 * r + 0.7151522 * g + 0.072175 * b,
+            0.0193339 * r + 0.119192 * g + 0.9503041 * b,
+        )
+    }
+}
+
+/// A color in CIE 1931 XYZ color space relative to the D65 white point,
+/// scaled so that the white of sRGB has a luminance `y` of 1.0
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Xyz {
+    x: f32,
+    y: f32,
+    z: f32,
+}
+
+impl Xyz {
+    pub fn new(x: f32, y: f32, z: f32) -> Self {
+        Xyz { x, y, z }
+    }
+
+    pub fn x(&self) -> f32 {
+        self.x
+    }
+
+    /// The relative luminance
+    pub fn y(&self) -> f32 {
+        self.y
+    }
+
+    pub fn z(&self) -> f32 {
+        self.z
+    }
+
+    /// Convert to a tuple of 3 floats
+    pub fn to_tuple(&self) -> (f32, f32, f32) {
+        (self.x, self.y, self.z)
+    }
+
+    /// Convert to linear RGB. The result may lie outside of the sRGB gamut
+    pub fn to_linear(&self) -> LinearRgb {
+        let (x, y, z) = (self.x, self.y, self.z);
+        LinearRgb::new(
+            3.2404542 * x - 1.5371385 * y - 0.4985314 * z,
+            -0.969266 * x + 1.8760108 * y + 0.041556 * z,
+            0.0556434 * x - 0.2040259 * y + 1.0572252 * z,
+        )
+    }
+
+    /// Convert to an sRGB `Color`, clamping colors outside of the gamut
+    pub fn to_color(&self) -> Color {
+        self.to_linear().to_color()
+    }
+
+    /// Convert to CIE L*a*b*
+    pub fn to_lab(&self) -> Lab {
+        let f = |t: f32| {
+            if t > EPSILON {
+                math::cbrt(t)
+            } else {
+                (KAPPA * t + 16.0) / 116.0
+            }
+        };
+        let fx = f(self.x / WHITE[0]);
+        let fy = f(self.y / WHITE[1]);
+        let fz = f(self.z / WHITE[2]);
+        Lab::new(116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz))
+    }
+}
+
+/// A color in CIE 1976 L*a*b* color space relative to the D65 white point.
+/// Lightness ranges from 0.0 to 100.0, `a` (green to red) and `b` (blue to yellow) are roughly within -128.0 to 128.0
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Lab {
+    l: f32,
+    a: f32,
+    b: f32,
+}
+
+impl Lab {
+    pub fn new(l: f32, a: f32, b: f32) -> Self {
+        Lab { l, a, b }
+    }
+
+    /// The perceptual lightness
+    pub fn l(&self) -> f32 {
+        self.l
+    }
+
+    pub fn a(&self) -> f32 {
+        self.a
+    }
+
+    pub fn b(&self) -> f32 {
+        self.b
+    }
+
+    /// Convert to a tuple of 3 floats
+    pub fn to_tuple(&self) -> (f32, f32, f32) {
+        (self.l, self.a, self.b)
+    }
+
+    /// Convert to CIE XYZ
+    pub fn to_xyz(&self) -> Xyz {
+        let fy = (self.l + 16.0) / 116.0;
+        let fx = fy + self.a / 500.0;
+        let fz = fy - self.b / 200.0;
+
+        let f_inv = |f: f32| {
+            let cube = f * f * f;
+            if cube > EPSILON {
+                cube
+            } else {
+                (116.0 * f - 16.0) / KAPPA
+            }
+        };
+        let y = if self.l > KAPPA * EPSILON {
+            fy * fy * fy
+        } else {
+            self.l / KAPPA
+        };
+        Xyz::new(f_inv(fx) * WHITE[0], y * WHITE[1], f_inv(fz) * WHITE[2])
+    }
+
+    /// Convert to the cylindrical LCh form
+    pub fn to_lch(&self) -> Lch {
+        Lch::new(self.l, math::hypot(self.a, self.b), math::atan2(self.b, self.a).to_degrees())
+    }
+
+    /// Convert to an sRGB `Color`, clamping colors outside of the gamut
+    pub fn to_color(&self) -> Color {
+        self.to_xyz().to_color()
+    }
+}
+
+/// A color in CIE LCh color space, the cylindrical form of L*a*b*.
+/// The hue is kept in degrees within 0.0 - 360.0 and wraps around
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Lch {
+    l: f32,
+    chroma: f32,
+    hue: Hue,
+}
+
+impl Lch {
+    pub fn new(l: f32, chroma: f32, hue: Hue) -> Self {
+        Lch {
+            l,
+            chroma,
+            hue: normalize_hue(hue),
+        }
+    }
+
+    /// The perceptual lightness
+    pub fn l(&self) -> f32 {
+        self.l
+    }
+
+    pub fn chroma(&self) -> f32 {
+        self.chroma
+    }
+
+    /// The hue in degrees, within 0.0 - 360.0
+    pub fn hue(&self) -> Hue {
+        self.hue
+    }
+
+    /// Convert to a tuple of 3 floats
+    pub fn to_tuple(&self) -> (f32, f32, Hue) {
+        (self.l, self.chroma, self.hue)
+    }
+
+    /// Convert to the rectangular L*a*b* form
+    pub fn to_lab(&self) -> Lab {
+        let hue = self.hue.to_radians();
+        Lab::new(self.l, self.chroma * math::cos(hue), self.chroma * math::sin(hue))
+    }
+
+    /// Convert to an sRGB `Color`, clamping colors outside of the gamut
+    pub fn to_color(&self) -> Color {
+        self.to_lab().to_color()
+    }
+}
+
+impl Color {
+    /// Remove the sRGB gamma curve
+    pub fn to_linear(&self) -> LinearRgb {
+        LinearRgb::new(srgb_to_linear(self.red()), srgb_to_linear(self.green()), srgb_to_linear(self.blue()))
+    }
+
+    /// Convert to CIE XYZ
+    pub fn to_xyz(&self) -> Xyz {
+        self.to_linear().to_xyz()
+    }
+
+    /// Convert to CIE L*a*b*
+    pub fn to_lab(&self) -> Lab {
+        self.to_xyz().to_lab()
+    }
+
+    /// Convert to CIE LCh
+    pub fn to_lch(&self) -> Lch {
+        self.to_lab().to_lch()
+    }
+}
+
+impl From<Color> for LinearRgb {
+    fn from(color: Color) -> Self {
+        color.to_linear()
+    }
+}
+
+impl From<LinearRgb> for Color {
+    fn from(linear: LinearRgb) -> Self {
+        linear.to_color()
+    }
+}
+
+impl From<Color> for Xyz {
+    fn from(color: Color) -> Self {
+        color.to_xyz()
+    }
+}
+
+impl From<Xyz> for Color {
+    fn from(xyz: Xyz) -> Self {
+        xyz.to_color()
+    }
+}
+
+impl From<Color> for Lab {
+    fn from(color: Color) -> Self {
+        color.to_lab()
+    }
+}
+
+impl From<Lab> for Color {
+    fn from(lab: Lab) -> Self {
+        lab.to_color()
+    }
+}
+
+impl From<Color> for Lch {
+    fn from(color: Color) -> Self {
+        color.to_lch()
+    }
+}
+
+impl From<Lch> for Color {
+    fn from(lch: Lch) -> Self {
+        lch.to_color()
+    }
+}
+
+impl From<Lab> for Lch {
+    fn from(lab: Lab) -> Self {
+        lab.to_lch()
+    }
+}
+
+impl From<Lch> for Lab {
+    fn from(lch: Lch) -> Self {
+        lch.to_lab()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{linear_to_srgb, srgb_to_linear};
+    use crate::Color;
+    use float_cmp::assert_approx_eq;
+
+    #[test]
+    fn test_transfer_function() {
+        for byte in 0..=255u8 {
+            let x = byte as f32 / 255.0;
+            assert_approx_eq!(f32, linear_to_srgb(srgb_to_linear(x)), x, epsilon = 0.00001);
+        }
+        assert_approx_eq!(f32, srgb_to_linear(0.5), 0.21404, epsilon = 0.00001);
+    }
+
+    #[test]
+    fn test_convert_lab() {
+        let (l, a, b) = Color::from_rgb_u8(255, 0, 0).to_lab().to_tuple();
+        assert_approx_eq!(f32, l, 53.2408, epsilon = 0.01);
+        assert_approx_eq!(f32, a, 80.0925, epsilon = 0.01);
+        assert_approx_eq!(f32, b, 67.2032, epsilon = 0.01);
+
+        let (l, a, b) = Color::from_rgb_u8(255, 255, 255).to_lab().to_tuple();
+        assert_approx_eq!(f32, l, 100.0, epsilon = 0.01);
+        assert_approx_eq!(f32, a, 0.0, epsilon = 0.01);
+        assert_approx_eq!(f32, b, 0.0, epsilon = 0.01);
+
+        let (l, chroma, hue) = Color::from_rgb_u8(0, 0, 255).to_lch().to_tuple();
+        assert_approx_eq!(f32, l, 32.2970, epsilon = 0.01);
+        assert_approx_eq!(f32, chroma, 133.8076, epsilon = 0.01);
+        assert_approx_eq!(f32, hue, 306.2849, epsilon = 0.01);
+
+        for (_, bytes) in crate::CSS_COLORS {
+            let color = Color::from(*bytes);
+            assert_eq!(color.to_lab().to_color().to_u8_array(), *bytes);
+            assert_eq!(color.to_lch().to_color().to_u8_array(), *bytes);
+        }
+    }
+}
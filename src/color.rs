@@ -1,10 +1,15 @@
-use std::format;
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String};
 
+use crate::precision::single::{self, clamp_unit};
 use crate::{Hue, Saturation, Value, Hsv};
 
 /// A simple struct containing the three main color components of RGB color space.
 /// Colors are stored as f32 values ranging from 0.0 to 1.0 
-#[derive(Copy, Clone, Debug)]
+/// 
+/// The RGB constructors and setters clamp their input into that range, and `NaN` is treated as 0.0,
+/// so a `Color` built from outside data always holds valid components.
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct Color {
     red: f32,
     green: f32,
@@ -12,6 +17,80 @@ pub struct Color {
 }
 
 impl Color {
+    /// Create a color from float components ranging from 0.0 to 1.0.
+    /// Values outside that range are clamped.
+    pub fn from_rgb_f32(red: f32, green: f32, blue: f32) -> Self {
+        Color {
+            red: clamp_unit(red),
+            green: clamp_unit(green),
+            blue: clamp_unit(blue)
+        }
+    }
+
+    /// Create a color from byte components ranging from 0 to 255
+    pub fn from_rgb_u8(red: u8, green: u8, blue: u8) -> Self {
+        Color {
+            red: red as f32 / 255.0,
+            green: green as f32 / 255.0,
+            blue: blue as f32 / 255.0
+        }
+    }
+
+    /// Create a color from an array of 3 floats, clamped like `from_rgb_f32`
+    pub fn from_array(rgb: [f32; 3]) -> Self {
+        Self::from_rgb_f32(rgb[0], rgb[1], rgb[2])
+    }
+
+    /// Create a color from a tuple of 3 floats, clamped like `from_rgb_f32`
+    pub fn from_tuple(rgb: (f32, f32, f32)) -> Self {
+        Self::from_rgb_f32(rgb.0, rgb.1, rgb.2)
+    }
+
+    /// The red component
+    pub fn red(&self) -> f32 {
+        self.red
+    }
+
+    /// The green component
+    pub fn green(&self) -> f32 {
+        self.green
+    }
+
+    /// The blue component
+    pub fn blue(&self) -> f32 {
+        self.blue
+    }
+
+    /// Return a copy with the red component replaced (clamped to 0.0 - 1.0)
+    pub fn with_red(self, red: f32) -> Self {
+        Color { red: clamp_unit(red), ..self }
+    }
+
+    /// Return a copy with the green component replaced (clamped to 0.0 - 1.0)
+    pub fn with_green(self, green: f32) -> Self {
+        Color { green: clamp_unit(green), ..self }
+    }
+
+    /// Return a copy with the blue component replaced (clamped to 0.0 - 1.0)
+    pub fn with_blue(self, blue: f32) -> Self {
+        Color { blue: clamp_unit(blue), ..self }
+    }
+
+    /// Set the red component (clamped to 0.0 - 1.0)
+    pub fn set_red(&mut self, red: f32) {
+        self.red = clamp_unit(red);
+    }
+
+    /// Set the green component (clamped to 0.0 - 1.0)
+    pub fn set_green(&mut self, green: f32) {
+        self.green = clamp_unit(green);
+    }
+
+    /// Set the blue component (clamped to 0.0 - 1.0)
+    pub fn set_blue(&mut self, blue: f32) {
+        self.blue = clamp_unit(blue);
+    }
+
     /// Convert to an array of 3 floats
     pub fn to_array(&self) -> [f32; 3] {
         [self.red, self.green, self.blue]
@@ -22,104 +101,115 @@ impl Color {
         (self.red, self.green, self.blue)
     }
 
-    /// Convert to an array for rgba (meaning it will just append 1.0 as the alpha value)
+    /// Convert to an array of 3 bytes, rounding each component to the nearest value
+    pub fn to_u8_array(&self) -> [u8; 3] {
+        self.to_rgb8().to_array()
+    }
+
+    /// Convert to an array for rgba (meaning it will just append 1.0 as the alpha value).
+    /// Use `with_alpha` to get an `Rgba` color with a different alpha value
     pub fn to_rgba_array(&self) -> [f32; 4] {
         [self.red, self.green, self.blue, 1.0]
     }
 
-    /// Convert HSV to RGB. Plain and simple
+    /// Convert HSV to RGB. Plain and simple.
+    /// The hue wraps around the color wheel, saturation and value are clamped to 0.0 - 1.0
     pub fn hsv_to_rgb(hue: Hue, saturation: Saturation, value: Value) -> Self {
-        let chroma = value * saturation;
-        let hue2 = hue / 60.0;
-        let tmp = chroma * (1.0 - ((hue2 % 2.0) - 1.0).abs());
-
-        let color2 = match hue2 {
-            h if (0.0..1.0).contains(&h) => (chroma, tmp, 0.0),
-            h if (1.0..2.0).contains(&h) => (tmp, chroma, 0.0),
-            h if (2.0..3.0).contains(&h) => (0.0, chroma, tmp),
-            h if (3.0..4.0).contains(&h) => (0.0, tmp, chroma),
-            h if (4.0..5.0).contains(&h) => (tmp, 0.0, chroma),
-            h if (5.0..6.0).contains(&h) => (chroma, 0.0, tmp),
-            _ => (0.0, 0.0, 0.0)
-        };
-
-        let m = value - chroma;
-        let red = color2.0 + m;
-        let green = color2.1 + m;
-        let blue = color2.2 + m;
-
-        Color {
-            red, 
-            green, 
-            blue
-        }
+        let (red, green, blue) = single::hsv_to_rgb(hue, saturation, value);
+        Color::from_rgb_f32(red, green, blue)
     }
 
-    /// Convert RGB to HSV
+    /// Convert RGB to HSV.
+    /// Grays have a hue of 0.0, and black also has a saturation of 0.0
     pub fn to_hsv(&self) -> Hsv {
-        let (r, g, b) = self.to_tuple();
-
-        let mut cmax = r;
-        let mut cmin = r;
-        if g > cmax { // f32 does not implement Ord so if tree it is
-            cmax = g;
-        } else if g < cmin {
-            cmin = g;
-        }
-        if b > cmax {
-            cmax = b;
-        } else if b < cmin {
-            cmin = b;
-        }
-        let delta = cmax - cmin;
+        let (hue, saturation, value) = single::rgb_to_hsv(self.red, self.green, self.blue);
+        Hsv::new(hue, saturation, value)
+    }
 
+    /// Convert the color to a hex string
+    #[cfg(feature = "alloc")]
+    pub fn to_hex(&self) -> String {
+        let [red, green, blue] = self.to_u8_array();
+        format!("#{:02X}{:02X}{:02X}", red, green, blue)
+    }
+}
 
+impl From<[f32; 3]> for Color {
+    fn from(rgb: [f32; 3]) -> Self {
+        Color::from_array(rgb)
+    }
+}
 
-        let hue = if cmax == r {
-            60.0 * (((g - b) / delta) % 6.0)
-        } else if cmax == g {
-            60.0 * (((b - r) / delta) + 2.0)
-        } else {
-            60.0 * (((r - g) / delta) + 4.0)
-        };
+impl From<(f32, f32, f32)> for Color {
+    fn from(rgb: (f32, f32, f32)) -> Self {
+        Color::from_tuple(rgb)
+    }
+}
 
-        let saturation = if cmax == 0.0 {
-            0.0
-        } else {
-            delta / cmax
-        };
-        (hue, saturation, cmax)
+impl From<(u8, u8, u8)> for Color {
+    fn from(rgb: (u8, u8, u8)) -> Self {
+        Color::from_rgb_u8(rgb.0, rgb.1, rgb.2)
     }
+}
 
-    /// Convert the color to a hex string
-    pub fn to_hex(&self) -> String {
-        format!("#{:02X}{:02X}{:02X}", (self.red * 255.0).round() as u32, (self.green * 255.0).round() as u32, (self.blue * 255.0).round() as u32)
+impl From<[u8; 3]> for Color {
+    fn from(rgb: [u8; 3]) -> Self {
+        Color::from_rgb_u8(rgb[0], rgb[1], rgb[2])
+    }
+}
+
+impl From<Color> for [f32; 3] {
+    fn from(color: Color) -> Self {
+        color.to_array()
+    }
+}
+
+impl From<Color> for [u8; 3] {
+    fn from(color: Color) -> Self {
+        color.to_u8_array()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::Color;
+    #[cfg(feature = "alloc")]
     use float_cmp::assert_approx_eq;
 
     #[test]
     fn test_convert_hsv_rgb() {
-        let colors = [
-            (20.85, 0.51, 0.7051166), 
-            (130.67574, 0.85, 0.51), 
-            (7.302415, 0.85, 0.7659915), 
-            (0.43018022, 0.11269033, 0.85)
-        ];
-
-        for (hue, saturation, value) in colors {
-            let color_obj = Color::hsv_to_rgb(hue, saturation, value);
-            let (hue2, saturation2, value2) = color_obj.to_hsv();
-            assert_approx_eq!(f32, hue, hue2, epsilon = 0.00003);
-            assert_approx_eq!(f32, saturation, saturation2, epsilon = 0.00003);
-            assert_approx_eq!(f32, value, value2, epsilon = 0.00003);
+        for red in 0..=255u8 {
+            for green in 0..=255u8 {
+                for blue in 0..=255u8 {
+                    let color_obj = Color::from_rgb_u8(red, green, blue);
+                    let hsv = color_obj.to_hsv();
+                    let (hue, saturation, value) = hsv.to_tuple();
+                    assert!((0.0..360.0).contains(&hue), "{:?} -> {:?}", color_obj, hsv);
+                    assert!((0.0..=1.0).contains(&saturation), "{:?} -> {:?}", color_obj, hsv);
+                    assert!((0.0..=1.0).contains(&value), "{:?} -> {:?}", color_obj, hsv);
+
+                    let bytes = Color::hsv_to_rgb(hue, saturation, value).to_u8_array();
+                    assert_eq!(bytes, [red, green, blue], "{:?} -> {:?}", color_obj, hsv);
+                }
+            }
         }
     }
 
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_convert_hsv_edge_cases() {
+        let (hue, saturation, _) = Color::from_rgb_u8(128, 128, 128).to_hsv().to_tuple();
+        assert_eq!((hue, saturation), (0.0, 0.0));
+        assert_eq!(Color::from_rgb_u8(0, 0, 0).to_hsv().to_tuple(), (0.0, 0.0, 0.0));
+        assert_eq!(Color::from_rgb_u8(255, 255, 255).to_hsv().to_tuple(), (0.0, 0.0, 1.0));
+        assert_approx_eq!(f32, Color::from_rgb_u8(255, 0, 1).to_hsv().hue(), 359.7647, epsilon = 0.0001);
+
+        assert_eq!(Color::hsv_to_rgb(360.0, 1.0, 1.0).to_hex(), "#FF0000");
+        assert_eq!(Color::hsv_to_rgb(-120.0, 1.0, 1.0).to_hex(), "#0000FF");
+        assert_eq!(Color::hsv_to_rgb(480.0, 1.0, 1.0).to_hex(), "#00FF00");
+    }
+
+    #[cfg(feature = "alloc")]
     #[test]
     fn test_convert_hex() {
         let mapping = [
@@ -136,4 +226,21 @@ mod tests {
             assert_eq!(hex, &hex2);
         }
     }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_rgb_constructors() {
+        let color = Color::from_rgb_f32(1.5, -0.2, f32::NAN);
+        assert_eq!(color.to_array(), [1.0, 0.0, 0.0]);
+
+        let color = Color::from_rgb_u8(64, 224, 207);
+        assert_eq!(color.to_hex(), "#40E0CF");
+        let bytes: [u8; 3] = color.into();
+        assert_eq!(bytes, [64, 224, 207]);
+        assert_eq!(Color::from((64u8, 224u8, 207u8)), color);
+
+        let color = Color::from([0.25, 0.5, 0.75]).with_red(2.0).with_blue(0.1);
+        assert_eq!(color.to_tuple(), (1.0, 0.5, 0.1));
+        assert_eq!(color.green(), 0.5);
+    }
 }
@@ -0,0 +1,170 @@
+use crate::math;
+use crate::{Color, Lab, Oklab};
+
+/// The methods `Color::delta_e` can use to measure how different two colors look.
+///
+/// The CIE methods return values where roughly 1.0 is the smallest difference a person can notice and
+/// anything above 2.0 to 3.0 is clearly visible. `Oklab` returns the euclidean distance in Oklab space, which is
+/// about 100 times smaller: a just noticeable difference there is roughly 0.01 to 0.02.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DeltaE {
+    /// Euclidean distance in L*a*b* (CIE 1976). Fast, but overstates differences between saturated colors
+    Cie76,
+    /// CIE 1994 with the weights for graphic arts
+    Cie94,
+    /// CIEDE2000, the most accurate of the CIE formulas
+    Ciede2000,
+    /// Euclidean distance in Oklab
+    Oklab,
+}
+
+impl Lab {
+    /// The CIE 1976 color difference, which is the euclidean distance between both colors
+    pub fn delta_e_76(&self, other: &Lab) -> f32 {
+        let dl = self.l() - other.l();
+        let da = self.a() - other.a();
+        let db = self.b() - other.b();
+        math::sqrt(dl * dl + da * da + db * db)
+    }
+
+    /// The CIE 1994 color difference using the graphic arts weights, with `self` as the reference color
+    pub fn delta_e_94(&self, other: &Lab) -> f32 {
+        let (k1, k2) = (0.045, 0.015);
+
+        let c1 = math::hypot(self.a(), self.b());
+        let c2 = math::hypot(other.a(), other.b());
+        let dl = self.l() - other.l();
+        let dc = c1 - c2;
+        let da = self.a() - other.a();
+        let db = self.b() - other.b();
+        let dh_squared = (da * da + db * db - dc * dc).max(0.0);
+
+        let sc = 1.0 + k1 * c1;
+        let sh = 1.0 + k2 * c1;
+        math::sqrt(dl * dl + math::powi(dc / sc, 2) + dh_squared / (sh * sh))
+    }
+
+    /// The CIEDE2000 color difference, see http://www2.ece.rochester.edu/~gsharma/ciede2000/
+    pub fn delta_e_2000(&self, other: &Lab) -> f32 {
+        let (l1, a1, b1) = self.to_tuple();
+        let (l2, a2, b2) = other.to_tuple();
+
+        let c_mean = (math::hypot(a1, b1) + math::hypot(a2, b2)) / 2.0;
+        let c_mean7 = math::powi(c_mean, 7);
+        let g = 0.5 * (1.0 - math::sqrt(c_mean7 / (c_mean7 + math::powi(25.0f32, 7))));
+
+        let a1 = a1 * (1.0 + g);
+        let a2 = a2 * (1.0 + g);
+        let c1 = math::hypot(a1, b1);
+        let c2 = math::hypot(a2, b2);
+        let hue = |a: f32, b: f32| if a == 0.0 && b == 0.0 { 0.0 } else { math::rem_euclid(math::atan2(b, a).to_degrees(), 360.0) };
+        let h1 = hue(a1, b1);
+        let h2 = hue(a2, b2);
+
+        let dl = l2 - l1;
+        let dc = c2 - c1;
+        let dh = if c1 * c2 == 0.0 {
+            0.0
+        } else if (h2 - h1).abs() <= 180.0 {
+            h2 - h1
+        } else if h2 - h1 > 180.0 {
+            h2 - h1 - 360.0
+        } else {
+            h2 - h1 + 360.0
+        };
+        let dh = 2.0 * math::sqrt(c1 * c2) * math::sin((dh / 2.0).to_radians());
+
+        let l_mean = (l1 + l2) / 2.0;
+        let c_mean = (c1 + c2) / 2.0;
+        let h_mean = if c1 * c2 == 0.0 {
+            h1 + h2
+        } else if (h1 - h2).abs() <= 180.0 {
+            (h1 + h2) / 2.0
+        } else if h1 + h2 < 360.0 {
+            (h1 + h2 + 360.0) / 2.0
+        } else {
+            (h1 + h2 - 360.0) / 2.0
+        };
+
+        let t = 1.0 - 0.17 * math::cos((h_mean - 30.0).to_radians())
+            + 0.24 * math::cos((2.0 * h_mean).to_radians())
+            + 0.32 * math::cos((3.0 * h_mean + 6.0).to_radians())
+            - 0.20 * math::cos((4.0 * h_mean - 63.0).to_radians());
+        let d_theta = 30.0 * math::exp(-math::powi((h_mean - 275.0) / 25.0, 2));
+        let c_mean7 = math::powi(c_mean, 7);
+        let rc = 2.0 * math::sqrt(c_mean7 / (c_mean7 + math::powi(25.0f32, 7)));
+        let l_offset = math::powi(l_mean - 50.0, 2);
+        let sl = 1.0 + 0.015 * l_offset / math::sqrt(20.0 + l_offset);
+        let sc = 1.0 + 0.045 * c_mean;
+        let sh = 1.0 + 0.015 * c_mean * t;
+        let rt = -math::sin((2.0 * d_theta).to_radians()) * rc;
+
+        let l_term = dl / sl;
+        let c_term = dc / sc;
+        let h_term = dh / sh;
+        math::sqrt(l_term * l_term + c_term * c_term + h_term * h_term + rt * c_term * h_term)
+    }
+}
+
+impl Oklab {
+    /// The euclidean distance between both colors in Oklab space
+    pub fn distance(&self, other: &Oklab) -> f32 {
+        let dl = self.l() - other.l();
+        let da = self.a() - other.a();
+        let db = self.b() - other.b();
+        math::sqrt(dl * dl + da * da + db * db)
+    }
+}
+
+impl Color {
+    /// Measure how different two colors look, using the given method.
+    /// `Cie94` is not symmetric, `self` is used as the reference color
+    pub fn delta_e(&self, other: &Color, method: DeltaE) -> f32 {
+        match method {
+            DeltaE::Cie76 => self.to_lab().delta_e_76(&other.to_lab()),
+            DeltaE::Cie94 => self.to_lab().delta_e_94(&other.to_lab()),
+            DeltaE::Ciede2000 => self.to_lab().delta_e_2000(&other.to_lab()),
+            DeltaE::Oklab => self.to_oklab().distance(&other.to_oklab()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DeltaE;
+    use crate::{Color, Lab};
+    use float_cmp::assert_approx_eq;
+
+    #[test]
+    fn test_ciede2000() {
+        // Test pairs from Sharma, Wu and Dalal, "The CIEDE2000 Color-Difference Formula"
+        let pairs = [
+            ((50.0, 2.6772, -79.7751), (50.0, 0.0, -82.7485), 2.0425),
+            ((50.0, 3.1571, -77.2803), (50.0, 0.0, -82.7485), 2.8615),
+            ((50.0, 0.0, 0.0), (50.0, -1.0, 2.0), 2.3669),
+            ((50.0, 2.5, 0.0), (73.0, 25.0, -18.0), 27.1492),
+            ((50.0, -0.001, 2.49), (50.0, 0.0009, -2.49), 4.8045),
+        ];
+
+        for ((l1, a1, b1), (l2, a2, b2), expected) in pairs {
+            let lab1 = Lab::new(l1, a1, b1);
+            let lab2 = Lab::new(l2, a2, b2);
+            assert_approx_eq!(f32, lab1.delta_e_2000(&lab2), expected, epsilon = 0.0005);
+            assert_approx_eq!(f32, lab2.delta_e_2000(&lab1), expected, epsilon = 0.0005);
+        }
+    }
+
+    #[test]
+    fn test_delta_e() {
+        let black = Color::from_rgb_u8(0, 0, 0);
+        let white = Color::from_rgb_u8(255, 255, 255);
+        let gray = Color::from_rgb_u8(128, 128, 128);
+
+        for method in [DeltaE::Cie76, DeltaE::Cie94, DeltaE::Ciede2000, DeltaE::Oklab] {
+            assert_eq!(gray.delta_e(&gray, method), 0.0);
+            assert!(black.delta_e(&white, method) > black.delta_e(&gray, method));
+        }
+        assert_approx_eq!(f32, black.delta_e(&white, DeltaE::Cie76), 100.0, epsilon = 0.01);
+        assert_approx_eq!(f32, black.delta_e(&white, DeltaE::Oklab), 1.0, epsilon = 0.001);
+    }
+}
@@ -0,0 +1,154 @@
+use alloc::vec::Vec;
+
+use crate::{Color, ColorPalette, DeltaE, HsvPalette, PaletteStrategy};
+
+/// How many candidates `DistinctPalette` draws for a single color before giving up
+const DEFAULT_ATTEMPTS: usize = 32;
+
+/// A palette which guarantees a minimum perceptual distance between all of the colors it emits.
+///
+/// For every color it draws candidates from the wrapped palette and skips those which are closer than
+/// `min_distance` to any color emitted before. Once the color space fills up and none of `max_attempts`
+/// candidates are far enough away, the candidate furthest from all previous colors is emitted anyway
+/// and counted in `saturated`, so the iterator stays infinite.
+///
+/// Create one with `ColorPalette::min_distance` or `HsvPalette::min_distance`.
+pub struct DistinctPalette<I> {
+    palette: I,
+    min_distance: f32,
+    method: DeltaE,
+    max_attempts: usize,
+    emitted: Vec<Color>,
+    saturated: usize,
+}
+
+impl<I> DistinctPalette<I>
+where
+    I: Iterator,
+    I::Item: Copy + Into<Color>,
+{
+    pub fn new(palette: I, min_distance: f32, method: DeltaE) -> Self {
+        DistinctPalette {
+            palette,
+            min_distance,
+            method,
+            max_attempts: DEFAULT_ATTEMPTS,
+            emitted: Vec::new(),
+            saturated: 0,
+        }
+    }
+
+    /// Set how many candidates are drawn for a single color before the palette counts as saturated
+    pub fn with_max_attempts(mut self, max_attempts: usize) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// The number of emitted colors which are closer than `min_distance` to an earlier color
+    pub fn saturated(&self) -> usize {
+        self.saturated
+    }
+
+    /// Whether the palette had to emit a color closer than `min_distance` to an earlier color
+    pub fn is_saturated(&self) -> bool {
+        self.saturated > 0
+    }
+
+    /// All colors emitted so far
+    pub fn emitted(&self) -> &[Color] {
+        &self.emitted
+    }
+
+    pub fn get_inner(&self) -> &I {
+        &self.palette
+    }
+
+    pub fn into_inner(self) -> I {
+        self.palette
+    }
+
+    /// The distance between `color` and the closest color emitted so far
+    fn nearest_distance(&self, color: &Color) -> f32 {
+        self.emitted
+            .iter()
+            .map(|emitted| emitted.delta_e(color, self.method))
+            .fold(f32::INFINITY, f32::min)
+    }
+}
+
+impl<I> Iterator for DistinctPalette<I>
+where
+    I: Iterator,
+    I::Item: Copy + Into<Color>,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut best: Option<(I::Item, Color, f32)> = None;
+
+        for _ in 0..self.max_attempts {
+            let candidate = self.palette.next()?;
+            let color = candidate.into();
+            let distance = self.nearest_distance(&color);
+
+            if distance >= self.min_distance {
+                self.emitted.push(color);
+                return Some(candidate);
+            }
+            if best.map_or(true, |(_, _, best_distance)| distance > best_distance) {
+                best = Some((candidate, color, distance));
+            }
+        }
+
+        let (candidate, color, _) = best?;
+        self.saturated += 1;
+        self.emitted.push(color);
+        Some(candidate)
+    }
+}
+
+impl<S: PaletteStrategy> ColorPalette<S> {
+    /// Only emit colors which are at least `min_distance` away from every earlier color, measured with `method`.
+    /// See `DistinctPalette` for what happens once no such color can be found
+    pub fn min_distance(self, min_distance: f32, method: DeltaE) -> DistinctPalette<ColorPalette<S>> {
+        DistinctPalette::new(self, min_distance, method)
+    }
+}
+
+impl<S: PaletteStrategy> HsvPalette<S> {
+    /// Only emit colors which are at least `min_distance` away from every earlier color, measured with `method`.
+    /// See `DistinctPalette` for what happens once no such color can be found
+    pub fn min_distance(self, min_distance: f32, method: DeltaE) -> DistinctPalette<HsvPalette<S>> {
+        DistinctPalette::new(self, min_distance, method)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ColorPalette, DeltaE, PaletteType};
+
+    #[test]
+    fn keeps_minimum_distance() {
+        let mut palette = ColorPalette::new(PaletteType::Random, true, &mut rand::thread_rng())
+            .min_distance(10.0, DeltaE::Ciede2000);
+        let colors: Vec<_> = palette.by_ref().take(12).collect();
+
+        assert_eq!(palette.saturated(), 0);
+        for (i, a) in colors.iter().enumerate() {
+            for b in &colors[i + 1..] {
+                assert!(a.delta_e(b, DeltaE::Ciede2000) >= 10.0);
+            }
+        }
+    }
+
+    #[test]
+    fn degrades_when_saturated() {
+        let mut palette = ColorPalette::new(PaletteType::Dark, false, &mut rand::thread_rng())
+            .min_distance(1000.0, DeltaE::Ciede2000)
+            .with_max_attempts(4);
+
+        assert_eq!(palette.by_ref().take(5).count(), 5);
+        assert_eq!(palette.saturated(), 4);
+        assert!(palette.is_saturated());
+    }
+}
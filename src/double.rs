@@ -0,0 +1,297 @@
+use crate::precision::double::{self, clamp_unit, normalize_hue};
+use crate::{Color, ColorPalette, Hsv, HsvPalette, PaletteStrategy, PaletteType};
+
+/// The `f64` twin of `Color`, for pipelines which work in double precision end to end.
+/// Components range from 0.0 to 1.0 and are clamped the same way, `NaN` becomes 0.0.
+///
+/// Converting from a `Color` is lossless, converting back rounds each component to the nearest `f32`.
+///
+/// Unlike `Color`, which serializes as a hex string, a `Color64` serializes as a struct of its exact
+/// `red`, `green` and `blue` components, the same format `serde_struct` uses for a `Color`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(from = "crate::serialize::Color64Fields"))]
+pub struct Color64 {
+    red: f64,
+    green: f64,
+    blue: f64,
+}
+
+/// The `f64` twin of `Hsv`, with the hue in degrees within 0.0 - 360.0
+/// and saturation and value within 0.0 - 1.0
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(from = "crate::serialize::Hsv64Fields"))]
+pub struct Hsv64 {
+    hue: f64,
+    saturation: f64,
+    value: f64,
+}
+
+impl Color64 {
+    /// Create a color from float components ranging from 0.0 to 1.0.
+    /// Values outside that range are clamped.
+    pub fn from_rgb_f64(red: f64, green: f64, blue: f64) -> Self {
+        Color64 {
+            red: clamp_unit(red),
+            green: clamp_unit(green),
+            blue: clamp_unit(blue),
+        }
+    }
+
+    /// Create a color from an array of 3 floats, clamped like `from_rgb_f64`
+    pub fn from_array(rgb: [f64; 3]) -> Self {
+        Self::from_rgb_f64(rgb[0], rgb[1], rgb[2])
+    }
+
+    /// The red component
+    pub fn red(&self) -> f64 {
+        self.red
+    }
+
+    /// The green component
+    pub fn green(&self) -> f64 {
+        self.green
+    }
+
+    /// The blue component
+    pub fn blue(&self) -> f64 {
+        self.blue
+    }
+
+    /// Convert to an array of 3 floats
+    pub fn to_array(&self) -> [f64; 3] {
+        [self.red, self.green, self.blue]
+    }
+
+    /// Convert to a tuple of 3 floats
+    pub fn to_tuple(&self) -> (f64, f64, f64) {
+        (self.red, self.green, self.blue)
+    }
+
+    /// Convert HSV to RGB, see `Color::hsv_to_rgb`
+    pub fn hsv_to_rgb(hue: f64, saturation: f64, value: f64) -> Self {
+        let (red, green, blue) = double::hsv_to_rgb(hue, saturation, value);
+        Color64::from_rgb_f64(red, green, blue)
+    }
+
+    /// Convert RGB to HSV, see `Color::to_hsv`
+    pub fn to_hsv(&self) -> Hsv64 {
+        let (hue, saturation, value) = double::rgb_to_hsv(self.red, self.green, self.blue);
+        Hsv64::new(hue, saturation, value)
+    }
+}
+
+impl Hsv64 {
+    /// Create a new HSV color, wrapping the hue and clamping saturation and value
+    pub fn new(hue: f64, saturation: f64, value: f64) -> Self {
+        Hsv64 {
+            hue: normalize_hue(hue),
+            saturation: clamp_unit(saturation),
+            value: clamp_unit(value),
+        }
+    }
+
+    /// The hue in degrees, within 0.0 - 360.0
+    pub fn hue(&self) -> f64 {
+        self.hue
+    }
+
+    /// The saturation, within 0.0 - 1.0
+    pub fn saturation(&self) -> f64 {
+        self.saturation
+    }
+
+    /// The value, within 0.0 - 1.0
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Convert to a tuple of hue, saturation and value
+    pub fn to_tuple(&self) -> (f64, f64, f64) {
+        (self.hue, self.saturation, self.value)
+    }
+
+    /// Convert to an RGB `Color64`
+    pub fn to_color(&self) -> Color64 {
+        Color64::hsv_to_rgb(self.hue, self.saturation, self.value)
+    }
+}
+
+impl From<[f64; 3]> for Color64 {
+    fn from(rgb: [f64; 3]) -> Self {
+        Color64::from_array(rgb)
+    }
+}
+
+impl From<Color64> for [f64; 3] {
+    fn from(color: Color64) -> Self {
+        color.to_array()
+    }
+}
+
+impl From<Color> for Color64 {
+    fn from(color: Color) -> Self {
+        Color64 {
+            red: color.red() as f64,
+            green: color.green() as f64,
+            blue: color.blue() as f64,
+        }
+    }
+}
+
+impl From<Color64> for Color {
+    fn from(color: Color64) -> Self {
+        Color::from_rgb_f32(color.red as f32, color.green as f32, color.blue as f32)
+    }
+}
+
+impl From<Hsv> for Hsv64 {
+    fn from(hsv: Hsv) -> Self {
+        Hsv64 {
+            hue: hsv.hue() as f64,
+            saturation: hsv.saturation() as f64,
+            value: hsv.value() as f64,
+        }
+    }
+}
+
+impl From<Hsv64> for Hsv {
+    fn from(hsv: Hsv64) -> Self {
+        Hsv::new(hsv.hue as f32, hsv.saturation as f32, hsv.value as f32)
+    }
+}
+
+impl From<Hsv64> for Color64 {
+    fn from(hsv: Hsv64) -> Self {
+        hsv.to_color()
+    }
+}
+
+impl From<Color64> for Hsv64 {
+    fn from(color: Color64) -> Self {
+        color.to_hsv()
+    }
+}
+
+/// A `ColorPalette` which emits `Color64` colors. Create one with `ColorPalette::into_f64`.
+///
+/// The palette still picks its hues, saturations and values in `f32`, which is what keeps seeded palettes stable,
+/// but converts them to RGB in `f64`. A color the palette starts with, like the one of `ColorPalette::from_color`,
+/// is emitted exactly. Just like `ColorPalette` this iterator is infinite.
+#[derive(Clone)]
+pub struct ColorPalette64<S = PaletteType> {
+    palette: ColorPalette<S>,
+}
+
+impl<S: PaletteStrategy> ColorPalette64<S> {
+    pub fn new(palette: ColorPalette<S>) -> Self {
+        ColorPalette64 { palette }
+    }
+
+    pub fn get_inner(&self) -> &ColorPalette<S> {
+        &self.palette
+    }
+
+    pub fn into_inner(self) -> ColorPalette<S> {
+        self.palette
+    }
+}
+
+impl<S: PaletteStrategy> ColorPalette<S> {
+    /// Turn this palette into one which emits `Color64` colors, see `ColorPalette64`
+    pub fn into_f64(self) -> ColorPalette64<S> {
+        ColorPalette64::new(self)
+    }
+}
+
+impl<S: PaletteStrategy> Iterator for ColorPalette64<S> {
+    type Item = Color64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let inner = &mut self.palette.0;
+        let first = inner.first.filter(|_| inner.iteration == 0).map(|(_, color)| Color64::from(color));
+        let hsv = inner.next()?;
+        Some(first.unwrap_or_else(|| Hsv64::from(hsv).to_color()))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let inner = &mut self.palette.0;
+        inner.seek(inner.iteration.saturating_add(n));
+        self.next()
+    }
+}
+
+/// An `HsvPalette` which emits `Hsv64` colors. Create one with `HsvPalette::into_f64`.
+///
+/// Like `ColorPalette64` the colors are generated in `f32` and then widened to `f64`.
+/// Just like `HsvPalette` this iterator is infinite.
+#[derive(Clone)]
+pub struct HsvPalette64<S = PaletteType> {
+    palette: HsvPalette<S>,
+}
+
+impl<S: PaletteStrategy> HsvPalette64<S> {
+    pub fn new(palette: HsvPalette<S>) -> Self {
+        HsvPalette64 { palette }
+    }
+
+    pub fn get_inner(&self) -> &HsvPalette<S> {
+        &self.palette
+    }
+
+    pub fn into_inner(self) -> HsvPalette<S> {
+        self.palette
+    }
+}
+
+impl<S: PaletteStrategy> HsvPalette<S> {
+    /// Turn this palette into one which emits `Hsv64` colors, see `HsvPalette64`
+    pub fn into_f64(self) -> HsvPalette64<S> {
+        HsvPalette64::new(self)
+    }
+}
+
+impl<S: PaletteStrategy> Iterator for HsvPalette64<S> {
+    type Item = Hsv64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.palette.next().map(Hsv64::from)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.palette.nth(n).map(Hsv64::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Color64, Hsv64};
+    use crate::{Color, ColorPalette, Hsv, PaletteType, Spread};
+
+    #[test]
+    fn converts_between_precisions() {
+        let color = Color::from_rgb_u8(64, 224, 207);
+        let wide = Color64::from(color);
+        assert_eq!(Color::from(wide), color);
+        assert_eq!(Color::from(wide.to_hsv().to_color()).to_u8_array(), [64, 224, 207]);
+
+        assert_eq!(Hsv64::new(-90.0, 1.5, f64::NAN).to_tuple(), (270.0, 1.0, 0.0));
+        assert_eq!(Color64::hsv_to_rgb(480.0, 1.0, 1.0).to_array(), [0.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn palette_emits_f64() {
+        let start = Color::from_rgb_f32(0.1, 0.25, 0.3);
+        let palette = ColorPalette::from_color(PaletteType::Pastel, start, Spread::Golden);
+        let expected: Vec<_> = palette.clone().take(5).collect();
+        let wide: Vec<_> = palette.clone().into_f64().take(5).collect();
+
+        assert_eq!(wide[0], Color64::from(start));
+        for (color, wide) in expected.iter().zip(&wide) {
+            assert_eq!(color.to_u8_array(), Color::from(*wide).to_u8_array());
+        }
+
+        let hsv: Vec<_> = palette.clone().into_inner().take(5).collect();
+        let wide: Vec<_> = palette.into_inner().into_f64().take(5).map(Hsv::from).collect();
+        assert_eq!(wide, hsv);
+    }
+}
@@ -0,0 +1,181 @@
+use alloc::vec::Vec;
+use core::ops::RangeInclusive;
+
+use rand::Rng;
+
+use crate::{Color, DeltaE, Oklch};
+
+/// The number of candidate colors `FarthestPointPalette` chooses from at every step
+const POOL_SIZE: usize = 256;
+
+/// A candidate color together with its distance to the closest color emitted so far
+struct Candidate {
+    color: Color,
+    distance: f32,
+}
+
+/// A palette which picks every new color as far away as possible from all the colors emitted before it
+/// (also known as max-min or farthest-point sampling). This is the usual technique for categorical data
+/// visualisation palettes and keeps colors much better separated than fixed hue steps do.
+///
+/// Candidates are spread evenly over an Oklch band of lightness and chroma using a low discrepancy sequence,
+/// and distances are measured with the configured `DeltaE` method. Just like `ColorPalette` this iterator is infinite,
+/// although new colors naturally get closer to existing ones the longer it runs.
+pub struct FarthestPointPalette {
+    lightness: RangeInclusive<f32>,
+    chroma: RangeInclusive<f32>,
+    method: DeltaE,
+    hue_offset: f32,
+    sequence: u32,
+    pool: Vec<Candidate>,
+    emitted: Vec<Color>,
+}
+
+/// The radical inverse of `index` in `base`, the building block of the Halton sequence
+fn radical_inverse(mut index: u32, base: u32) -> f32 {
+    let mut result = 0.0;
+    let mut fraction = 1.0 / base as f32;
+    while index > 0 {
+        result += (index % base) as f32 * fraction;
+        index /= base;
+        fraction /= base as f32;
+    }
+    result
+}
+
+/// Linearly map `t` from 0.0 - 1.0 into `range`
+fn lerp(range: &RangeInclusive<f32>, t: f32) -> f32 {
+    range.start() + (range.end() - range.start()) * t
+}
+
+impl FarthestPointPalette {
+    /// Create a new palette with a random starting hue, colors of medium lightness and chroma,
+    /// and distances measured in Oklab
+    pub fn new<T: Rng>(rng: &mut T) -> Self {
+        FarthestPointPalette {
+            lightness: 0.45..=0.85,
+            chroma: 0.08..=0.2,
+            method: DeltaE::Oklab,
+            hue_offset: rng.gen_range(0.0..360.0),
+            sequence: 0,
+            pool: Vec::new(),
+            emitted: Vec::new(),
+        }
+    }
+
+    /// Restrict the Oklch lightness (0.0 - 1.0) of the generated colors
+    pub fn with_lightness(mut self, lightness: RangeInclusive<f32>) -> Self {
+        self.lightness = lightness;
+        self.reset();
+        self
+    }
+
+    /// Restrict the Oklch chroma (0.0 to about 0.37) of the generated colors.
+    /// Colors outside of the sRGB gamut get their chroma reduced until they fit
+    pub fn with_chroma(mut self, chroma: RangeInclusive<f32>) -> Self {
+        self.chroma = chroma;
+        self.reset();
+        self
+    }
+
+    /// Set the method used to measure the distance between colors, `DeltaE::Cie76` measures in L*a*b*
+    pub fn with_method(mut self, method: DeltaE) -> Self {
+        self.method = method;
+        self.reset();
+        self
+    }
+
+    /// All colors emitted so far
+    pub fn emitted(&self) -> &[Color] {
+        &self.emitted
+    }
+
+    /// Forget all emitted colors and candidates, so the palette starts over from its starting hue
+    fn reset(&mut self) {
+        self.sequence = 0;
+        self.pool.clear();
+        self.emitted.clear();
+    }
+
+    /// Generate the next candidate from the Halton sequence and measure it against the emitted colors
+    fn candidate(&mut self) -> Candidate {
+        self.sequence += 1;
+        let hue = self.hue_offset + 360.0 * radical_inverse(self.sequence, 2);
+        let lightness = lerp(&self.lightness, radical_inverse(self.sequence, 3));
+        let chroma = lerp(&self.chroma, radical_inverse(self.sequence, 5));
+
+        let color = Oklch::new(lightness, chroma, hue).to_color_in_gamut();
+        let distance = self
+            .emitted
+            .iter()
+            .map(|emitted| emitted.delta_e(&color, self.method))
+            .fold(f32::INFINITY, f32::min);
+        Candidate { color, distance }
+    }
+}
+
+impl Iterator for FarthestPointPalette {
+    type Item = Color;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.pool.len() < POOL_SIZE {
+            let candidate = self.candidate();
+            self.pool.push(candidate);
+        }
+
+        let mut farthest = 0;
+        for (i, candidate) in self.pool.iter().enumerate() {
+            if candidate.distance > self.pool[farthest].distance {
+                farthest = i;
+            }
+        }
+        let color = self.pool[farthest].color;
+
+        self.emitted.push(color);
+        for candidate in &mut self.pool {
+            candidate.distance = candidate.distance.min(candidate.color.delta_e(&color, self.method));
+        }
+        self.pool[farthest] = self.candidate();
+
+        Some(color)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FarthestPointPalette;
+    use crate::{ColorPalette, DeltaE, PaletteType};
+
+    /// The smallest distance between any two of the colors
+    fn min_distance(colors: &[crate::Color]) -> f32 {
+        let mut min = f32::INFINITY;
+        for (i, a) in colors.iter().enumerate() {
+            for b in &colors[i + 1..] {
+                min = min.min(a.delta_e(b, DeltaE::Oklab));
+            }
+        }
+        min
+    }
+
+    #[test]
+    fn separates_colors() {
+        let farthest: Vec<_> = FarthestPointPalette::new(&mut rand::thread_rng()).take(12).collect();
+        let stepped: Vec<_> = ColorPalette::new(PaletteType::Oklch, false, &mut rand::thread_rng()).take(12).collect();
+
+        assert!(min_distance(&farthest) > min_distance(&stepped));
+    }
+
+    #[test]
+    fn stays_in_band() {
+        let palette = FarthestPointPalette::new(&mut rand::thread_rng())
+            .with_lightness(0.6..=0.7)
+            .with_chroma(0.05..=0.1)
+            .with_method(DeltaE::Cie76);
+
+        for color in palette.take(20) {
+            let oklch = color.to_oklch();
+            assert!((0.59..=0.71).contains(&oklch.l()));
+            assert!(oklch.chroma() <= 0.11);
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use crate::{Color, Hsv, Hue};
+
+/// Classic color harmony rules, each describing a set of hues relative to a base hue
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Harmony {
+    /// The base hue and the one opposite to it
+    Complementary,
+    /// Three hues evenly spaced around the color wheel
+    Triadic,
+    /// Two pairs of complementary hues forming a rectangle on the color wheel
+    Tetradic,
+    /// Four hues evenly spaced around the color wheel
+    Square,
+    /// The base hue and the two hues next to its complement
+    SplitComplementary,
+    /// The base hue and its two neighbours
+    Analogous,
+}
+
+impl Harmony {
+    /// The hue offsets in degrees from the base hue, starting with the base hue itself
+    pub fn offsets(&self) -> &'static [Hue] {
+        match self {
+            Harmony::Complementary => &[0.0, 180.0],
+            Harmony::Triadic => &[0.0, 120.0, 240.0],
+            Harmony::Tetradic => &[0.0, 60.0, 180.0, 240.0],
+            Harmony::Square => &[0.0, 90.0, 180.0, 270.0],
+            Harmony::SplitComplementary => &[0.0, 150.0, 210.0],
+            Harmony::Analogous => &[0.0, 30.0, 330.0],
+        }
+    }
+}
+
+impl Hsv {
+    /// The colors forming the given harmony with this color, starting with this color itself.
+    /// Only the hue changes, saturation and value stay the same
+    pub fn harmony(&self, harmony: Harmony) -> impl Iterator<Item = Hsv> {
+        let base = *self;
+        harmony
+            .offsets()
+            .iter()
+            .map(move |offset| Hsv::new(base.hue() + offset, base.saturation(), base.value()))
+    }
+}
+
+impl Color {
+    /// The colors forming the given harmony with this color, starting with this color itself.
+    /// The hues are rotated in HSV, saturation and value stay the same
+    pub fn harmony(&self, harmony: Harmony) -> impl Iterator<Item = Color> {
+        self.to_hsv().harmony(harmony).map(Color::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Harmony;
+    use crate::{ColorPalette, HsvPalette, PaletteType};
+    #[cfg(feature = "alloc")]
+    use crate::{Color, Hsv};
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_harmony() {
+        let red = Color::from_rgb_u8(255, 0, 0);
+        let hex: Vec<_> = red.harmony(Harmony::Triadic).map(|color| color.to_hex()).collect();
+        assert_eq!(hex, ["#FF0000", "#00FF00", "#0000FF"]);
+
+        let hues: Vec<_> = Hsv::new(350.0, 0.5, 0.5).harmony(Harmony::Analogous).map(|hsv| hsv.hue()).collect();
+        assert_eq!(hues, [350.0, 20.0, 320.0]);
+    }
+
+    #[test]
+    fn test_harmony_palette() {
+        let mut palette = HsvPalette::new(PaletteType::Harmony(Harmony::Square), false, &mut rand::thread_rng());
+        let first = palette.next().unwrap();
+        let expected: Vec<_> = first.harmony(Harmony::Square).map(|hsv| hsv.hue()).collect();
+
+        let mut hues = vec![first.hue()];
+        hues.extend(palette.by_ref().take(3).map(|hsv| hsv.hue()));
+        for (hue, expected) in hues.iter().zip(&expected) {
+            assert!((hue - expected).abs() < 0.001);
+        }
+
+        let colors = ColorPalette::new(PaletteType::Harmony(Harmony::Complementary), false, &mut rand::thread_rng());
+        assert_eq!(colors.take(50).count(), 50);
+    }
+}
@@ -0,0 +1,123 @@
+use crate::{Color, Hsv, Hue, Saturation};
+use crate::precision::single::{clamp_unit, normalize_hue};
+
+/// A color in HSL color space, the model used by CSS `hsl()`.
+///
+/// The hue is kept in degrees within 0.0 - 360.0 and wraps around the color wheel,
+/// saturation and lightness are clamped to 0.0 - 1.0.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Hsl {
+    hue: Hue,
+    saturation: Saturation,
+    lightness: f32,
+}
+
+impl Hsl {
+    /// Create a new HSL color, wrapping the hue and clamping saturation and lightness
+    pub fn new(hue: Hue, saturation: Saturation, lightness: f32) -> Self {
+        Hsl {
+            hue: normalize_hue(hue),
+            saturation: clamp_unit(saturation),
+            lightness: clamp_unit(lightness),
+        }
+    }
+
+    /// The hue in degrees, within 0.0 - 360.0
+    pub fn hue(&self) -> Hue {
+        self.hue
+    }
+
+    /// The saturation, within 0.0 - 1.0
+    pub fn saturation(&self) -> Saturation {
+        self.saturation
+    }
+
+    /// The lightness, within 0.0 - 1.0
+    pub fn lightness(&self) -> f32 {
+        self.lightness
+    }
+
+    /// Convert to a tuple of hue, saturation and lightness
+    pub fn to_tuple(&self) -> (Hue, Saturation, f32) {
+        (self.hue, self.saturation, self.lightness)
+    }
+
+    /// Convert to HSV, see https://en.wikipedia.org/wiki/HSL_and_HSV#HSL_to_HSV
+    pub fn to_hsv(&self) -> Hsv {
+        let value = self.lightness + self.saturation * self.lightness.min(1.0 - self.lightness);
+        let saturation = if value == 0.0 {
+            0.0
+        } else {
+            2.0 * (1.0 - self.lightness / value)
+        };
+        Hsv::new(self.hue, saturation, value)
+    }
+
+    /// Convert to an RGB `Color`
+    pub fn to_color(&self) -> Color {
+        self.to_hsv().to_color()
+    }
+}
+
+impl Hsv {
+    /// Convert to HSL, see https://en.wikipedia.org/wiki/HSL_and_HSV#HSV_to_HSL
+    pub fn to_hsl(&self) -> Hsl {
+        let lightness = self.value() * (1.0 - self.saturation() / 2.0);
+        let saturation = if lightness == 0.0 || lightness == 1.0 {
+            0.0
+        } else {
+            (self.value() - lightness) / lightness.min(1.0 - lightness)
+        };
+        Hsl::new(self.hue(), saturation, lightness)
+    }
+}
+
+impl Color {
+    /// Convert RGB to HSL
+    pub fn to_hsl(&self) -> Hsl {
+        self.to_hsv().to_hsl()
+    }
+}
+
+impl From<Hsv> for Hsl {
+    fn from(hsv: Hsv) -> Self {
+        hsv.to_hsl()
+    }
+}
+
+impl From<Hsl> for Hsv {
+    fn from(hsl: Hsl) -> Self {
+        hsl.to_hsv()
+    }
+}
+
+impl From<Color> for Hsl {
+    fn from(color: Color) -> Self {
+        color.to_hsl()
+    }
+}
+
+impl From<Hsl> for Color {
+    fn from(hsl: Hsl) -> Self {
+        hsl.to_color()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Color;
+
+    #[test]
+    fn test_convert_hsl() {
+        let (hue, saturation, lightness) = Color::from_rgb_u8(0x66, 0x33, 0x99).to_hsl().to_tuple();
+        assert_eq!(hue.round(), 270.0);
+        assert_eq!((saturation * 100.0).round(), 50.0);
+        assert_eq!((lightness * 100.0).round(), 40.0);
+
+        for (_, bytes) in crate::CSS_COLORS {
+            let color = Color::from(*bytes);
+            assert_eq!(color.to_hsl().to_color().to_u8_array(), *bytes);
+            assert_eq!(color.to_hsl().to_hsv().to_hsl(), color.to_hsl());
+        }
+    }
+}
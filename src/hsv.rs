@@ -0,0 +1,90 @@
+use crate::{Color, Hue, Saturation, Value};
+use crate::precision::single::{clamp_unit, normalize_hue};
+
+/// A color in HSV color space.
+///
+/// The hue is kept in degrees within 0.0 (inclusive) to 360.0 (exclusive), any other hue is wrapped
+/// around the color wheel. Saturation and value are clamped to 0.0 - 1.0 and `NaN` components become 0.0.
+/// Converting an achromatic `Color` (grays, including black and white) gives a hue of 0.0,
+/// and converting black additionally gives a saturation of 0.0.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(from = "crate::serialize::HsvFields"))]
+pub struct Hsv {
+    hue: Hue,
+    saturation: Saturation,
+    value: Value,
+}
+
+impl Hsv {
+    /// Create a new HSV color, wrapping the hue and clamping saturation and value
+    pub fn new(hue: Hue, saturation: Saturation, value: Value) -> Self {
+        Hsv {
+            hue: normalize_hue(hue),
+            saturation: clamp_unit(saturation),
+            value: clamp_unit(value),
+        }
+    }
+
+    /// The hue in degrees, within 0.0 - 360.0
+    pub fn hue(&self) -> Hue {
+        self.hue
+    }
+
+    /// The saturation, within 0.0 - 1.0
+    pub fn saturation(&self) -> Saturation {
+        self.saturation
+    }
+
+    /// The value, within 0.0 - 1.0
+    pub fn value(&self) -> Value {
+        self.value
+    }
+
+    /// Convert to a tuple of hue, saturation and value
+    pub fn to_tuple(&self) -> (Hue, Saturation, Value) {
+        (self.hue, self.saturation, self.value)
+    }
+
+    /// Convert to an RGB `Color`
+    pub fn to_color(&self) -> Color {
+        Color::hsv_to_rgb(self.hue, self.saturation, self.value)
+    }
+}
+
+impl From<(Hue, Saturation, Value)> for Hsv {
+    fn from(hsv: (Hue, Saturation, Value)) -> Self {
+        Hsv::new(hsv.0, hsv.1, hsv.2)
+    }
+}
+
+impl From<Hsv> for (Hue, Saturation, Value) {
+    fn from(hsv: Hsv) -> Self {
+        hsv.to_tuple()
+    }
+}
+
+impl From<Hsv> for Color {
+    fn from(hsv: Hsv) -> Self {
+        hsv.to_color()
+    }
+}
+
+impl From<Color> for Hsv {
+    fn from(color: Color) -> Self {
+        color.to_hsv()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Hsv;
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(Hsv::new(360.0, 0.5, 0.5).hue(), 0.0);
+        assert_eq!(Hsv::new(-90.0, 0.5, 0.5).hue(), 270.0);
+        assert_eq!(Hsv::new(-1e-6, 0.5, 0.5).hue(), 0.0);
+        assert_eq!(Hsv::new(765.0, 0.5, 0.5).hue(), 45.0);
+        assert_eq!(Hsv::new(f32::NAN, 1.5, -0.5).to_tuple(), (0.0, 1.0, 0.0));
+    }
+}
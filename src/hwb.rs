@@ -0,0 +1,117 @@
+use crate::{Color, Hsv, Hue};
+use crate::precision::single::{clamp_unit, normalize_hue};
+
+/// A color in HWB (hue, whiteness, blackness) color space, the model used by CSS `hwb()`.
+///
+/// The hue is kept in degrees within 0.0 - 360.0 and wraps around the color wheel,
+/// whiteness and blackness are clamped to 0.0 - 1.0. When whiteness and blackness add up to more than 1.0
+/// the color is a gray, and converting it normalises both so they add up to exactly 1.0.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Hwb {
+    hue: Hue,
+    whiteness: f32,
+    blackness: f32,
+}
+
+impl Hwb {
+    /// Create a new HWB color, wrapping the hue and clamping whiteness and blackness
+    pub fn new(hue: Hue, whiteness: f32, blackness: f32) -> Self {
+        Hwb {
+            hue: normalize_hue(hue),
+            whiteness: clamp_unit(whiteness),
+            blackness: clamp_unit(blackness),
+        }
+    }
+
+    /// The hue in degrees, within 0.0 - 360.0
+    pub fn hue(&self) -> Hue {
+        self.hue
+    }
+
+    /// The amount of white mixed into the color, within 0.0 - 1.0
+    pub fn whiteness(&self) -> f32 {
+        self.whiteness
+    }
+
+    /// The amount of black mixed into the color, within 0.0 - 1.0
+    pub fn blackness(&self) -> f32 {
+        self.blackness
+    }
+
+    /// Convert to a tuple of hue, whiteness and blackness
+    pub fn to_tuple(&self) -> (Hue, f32, f32) {
+        (self.hue, self.whiteness, self.blackness)
+    }
+
+    /// Convert to HSV
+    pub fn to_hsv(&self) -> Hsv {
+        let sum = self.whiteness + self.blackness;
+        if sum >= 1.0 {
+            return Hsv::new(self.hue, 0.0, self.whiteness / sum);
+        }
+
+        let value = 1.0 - self.blackness;
+        Hsv::new(self.hue, 1.0 - self.whiteness / value, value)
+    }
+
+    /// Convert to an RGB `Color`
+    pub fn to_color(&self) -> Color {
+        self.to_hsv().to_color()
+    }
+}
+
+impl Hsv {
+    /// Convert to HWB
+    pub fn to_hwb(&self) -> Hwb {
+        Hwb::new(self.hue(), (1.0 - self.saturation()) * self.value(), 1.0 - self.value())
+    }
+}
+
+impl Color {
+    /// Convert RGB to HWB
+    pub fn to_hwb(&self) -> Hwb {
+        self.to_hsv().to_hwb()
+    }
+}
+
+impl From<Hsv> for Hwb {
+    fn from(hsv: Hsv) -> Self {
+        hsv.to_hwb()
+    }
+}
+
+impl From<Hwb> for Hsv {
+    fn from(hwb: Hwb) -> Self {
+        hwb.to_hsv()
+    }
+}
+
+impl From<Color> for Hwb {
+    fn from(color: Color) -> Self {
+        color.to_hwb()
+    }
+}
+
+impl From<Hwb> for Color {
+    fn from(hwb: Hwb) -> Self {
+        hwb.to_color()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Hwb;
+    use crate::Color;
+
+    #[test]
+    fn test_convert_hwb() {
+        let (hue, whiteness, blackness) = Color::from_rgb_u8(255, 128, 0).to_hwb().to_tuple();
+        assert_eq!((hue.round(), whiteness, blackness), (30.0, 0.0, 0.0));
+        assert_eq!(Hwb::new(0.0, 0.6, 0.6).to_color(), Color::from_rgb_f32(0.5, 0.5, 0.5));
+
+        for (_, bytes) in crate::CSS_COLORS {
+            let color = Color::from(*bytes);
+            assert_eq!(color.to_hwb().to_color().to_u8_array(), *bytes);
+        }
+    }
+}
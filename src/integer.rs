@@ -0,0 +1,315 @@
+use core::marker::PhantomData;
+
+use crate::math;
+use crate::{Color, ColorPalette, PaletteStrategy, PaletteType, Rgba};
+use crate::precision::single::clamp_unit;
+
+/// An RGB color with 8 bit channels, as used by most image buffers and terminals
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rgb8 {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+}
+
+/// An RGB color with 16 bit channels, for example for 16 bit PNGs or textures
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rgb16 {
+    pub red: u16,
+    pub green: u16,
+    pub blue: u16,
+}
+
+/// An RGBA color with 8 bit channels and straight (not premultiplied) alpha
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rgba8 {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+    pub alpha: u8,
+}
+
+/// An RGBA color with 16 bit channels and straight (not premultiplied) alpha
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rgba16 {
+    pub red: u16,
+    pub green: u16,
+    pub blue: u16,
+    pub alpha: u16,
+}
+
+/// Convert a float component to a byte, clamping it to 0.0 - 1.0 and rounding to the nearest value
+pub(crate) fn unit_to_u8(x: f32) -> u8 {
+    math::round(clamp_unit(x) * 255.0) as u8
+}
+
+/// Convert a float component to 16 bits, clamping it to 0.0 - 1.0 and rounding to the nearest value
+pub(crate) fn unit_to_u16(x: f32) -> u16 {
+    math::round(clamp_unit(x) * 65535.0) as u16
+}
+
+impl Rgb8 {
+    pub fn new(red: u8, green: u8, blue: u8) -> Self {
+        Rgb8 { red, green, blue }
+    }
+
+    /// Convert to an array of 3 bytes
+    pub fn to_array(&self) -> [u8; 3] {
+        [self.red, self.green, self.blue]
+    }
+
+    /// Attach an alpha value to this color
+    pub fn with_alpha(self, alpha: u8) -> Rgba8 {
+        Rgba8::new(self.red, self.green, self.blue, alpha)
+    }
+}
+
+impl Rgb16 {
+    pub fn new(red: u16, green: u16, blue: u16) -> Self {
+        Rgb16 { red, green, blue }
+    }
+
+    /// Convert to an array of 3 16 bit values
+    pub fn to_array(&self) -> [u16; 3] {
+        [self.red, self.green, self.blue]
+    }
+
+    /// Attach an alpha value to this color
+    pub fn with_alpha(self, alpha: u16) -> Rgba16 {
+        Rgba16::new(self.red, self.green, self.blue, alpha)
+    }
+}
+
+impl Rgba8 {
+    pub fn new(red: u8, green: u8, blue: u8, alpha: u8) -> Self {
+        Rgba8 { red, green, blue, alpha }
+    }
+
+    /// Convert to an array of 4 bytes
+    pub fn to_array(&self) -> [u8; 4] {
+        [self.red, self.green, self.blue, self.alpha]
+    }
+}
+
+impl Rgba16 {
+    pub fn new(red: u16, green: u16, blue: u16, alpha: u16) -> Self {
+        Rgba16 { red, green, blue, alpha }
+    }
+
+    /// Convert to an array of 4 16 bit values
+    pub fn to_array(&self) -> [u16; 4] {
+        [self.red, self.green, self.blue, self.alpha]
+    }
+}
+
+impl Color {
+    /// Convert to 8 bit channels, rounding each component to the nearest value
+    pub fn to_rgb8(&self) -> Rgb8 {
+        Rgb8::new(unit_to_u8(self.red()), unit_to_u8(self.green()), unit_to_u8(self.blue()))
+    }
+
+    /// Convert to 16 bit channels, rounding each component to the nearest value
+    pub fn to_rgb16(&self) -> Rgb16 {
+        Rgb16::new(unit_to_u16(self.red()), unit_to_u16(self.green()), unit_to_u16(self.blue()))
+    }
+}
+
+impl Rgba {
+    /// Convert to 8 bit channels, rounding each component to the nearest value
+    pub fn to_rgba8(&self) -> Rgba8 {
+        self.color().to_rgb8().with_alpha(unit_to_u8(self.alpha()))
+    }
+
+    /// Convert to 16 bit channels, rounding each component to the nearest value
+    pub fn to_rgba16(&self) -> Rgba16 {
+        self.color().to_rgb16().with_alpha(unit_to_u16(self.alpha()))
+    }
+}
+
+impl From<Color> for Rgb8 {
+    fn from(color: Color) -> Self {
+        color.to_rgb8()
+    }
+}
+
+impl From<Color> for Rgb16 {
+    fn from(color: Color) -> Self {
+        color.to_rgb16()
+    }
+}
+
+impl From<Rgba> for Rgba8 {
+    fn from(rgba: Rgba) -> Self {
+        rgba.to_rgba8()
+    }
+}
+
+impl From<Rgba> for Rgba16 {
+    fn from(rgba: Rgba) -> Self {
+        rgba.to_rgba16()
+    }
+}
+
+impl From<Rgb8> for Color {
+    fn from(rgb: Rgb8) -> Self {
+        Color::from_rgb_u8(rgb.red, rgb.green, rgb.blue)
+    }
+}
+
+impl From<Rgb16> for Color {
+    fn from(rgb: Rgb16) -> Self {
+        let [red, green, blue] = rgb.to_array().map(|x| x as f32 / 65535.0);
+        Color::from_rgb_f32(red, green, blue)
+    }
+}
+
+impl From<Rgba8> for Rgba {
+    fn from(rgba: Rgba8) -> Self {
+        Rgba::from_rgba_u8(rgba.red, rgba.green, rgba.blue, rgba.alpha)
+    }
+}
+
+impl From<Rgba16> for Rgba {
+    fn from(rgba: Rgba16) -> Self {
+        let [red, green, blue, alpha] = rgba.to_array().map(|x| x as f32 / 65535.0);
+        Rgba::from_rgba_f32(red, green, blue, alpha)
+    }
+}
+
+impl From<[u8; 3]> for Rgb8 {
+    fn from(rgb: [u8; 3]) -> Self {
+        Rgb8::new(rgb[0], rgb[1], rgb[2])
+    }
+}
+
+impl From<Rgb8> for [u8; 3] {
+    fn from(rgb: Rgb8) -> Self {
+        rgb.to_array()
+    }
+}
+
+impl From<[u16; 3]> for Rgb16 {
+    fn from(rgb: [u16; 3]) -> Self {
+        Rgb16::new(rgb[0], rgb[1], rgb[2])
+    }
+}
+
+impl From<Rgb16> for [u16; 3] {
+    fn from(rgb: Rgb16) -> Self {
+        rgb.to_array()
+    }
+}
+
+impl From<[u8; 4]> for Rgba8 {
+    fn from(rgba: [u8; 4]) -> Self {
+        Rgba8::new(rgba[0], rgba[1], rgba[2], rgba[3])
+    }
+}
+
+impl From<Rgba8> for [u8; 4] {
+    fn from(rgba: Rgba8) -> Self {
+        rgba.to_array()
+    }
+}
+
+impl From<[u16; 4]> for Rgba16 {
+    fn from(rgba: [u16; 4]) -> Self {
+        Rgba16::new(rgba[0], rgba[1], rgba[2], rgba[3])
+    }
+}
+
+impl From<Rgba16> for [u16; 4] {
+    fn from(rgba: Rgba16) -> Self {
+        rgba.to_array()
+    }
+}
+
+/// A `ColorPalette` which emits integer colors such as `Rgb8` or `Rgb16` directly.
+/// Create one with `ColorPalette::into_rgb8` or `ColorPalette::into_rgb16`.
+/// Just like `ColorPalette` this iterator is infinite.
+pub struct IntegerPalette<T, S = PaletteType> {
+    palette: ColorPalette<S>,
+    format: PhantomData<T>,
+}
+
+impl<T: From<Color>, S: PaletteStrategy> IntegerPalette<T, S> {
+    pub fn new(palette: ColorPalette<S>) -> Self {
+        IntegerPalette {
+            palette,
+            format: PhantomData,
+        }
+    }
+
+    pub fn get_inner(&self) -> &ColorPalette<S> {
+        &self.palette
+    }
+
+    pub fn into_inner(self) -> ColorPalette<S> {
+        self.palette
+    }
+}
+
+impl<T, S: Clone> Clone for IntegerPalette<T, S> {
+    fn clone(&self) -> Self {
+        IntegerPalette {
+            palette: self.palette.clone(),
+            format: PhantomData,
+        }
+    }
+}
+
+impl<S: PaletteStrategy> ColorPalette<S> {
+    /// Turn this palette into one which emits `Rgb8` colors, see `IntegerPalette`
+    pub fn into_rgb8(self) -> IntegerPalette<Rgb8, S> {
+        IntegerPalette::new(self)
+    }
+
+    /// Turn this palette into one which emits `Rgb16` colors, see `IntegerPalette`
+    pub fn into_rgb16(self) -> IntegerPalette<Rgb16, S> {
+        IntegerPalette::new(self)
+    }
+}
+
+impl<T: From<Color>, S: PaletteStrategy> Iterator for IntegerPalette<T, S> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.palette.next().map(T::from)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.palette.nth(n).map(T::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Rgb16, Rgb8, Rgba8};
+    use crate::{Color, ColorPalette, PaletteType, Rgba, Spread};
+
+    #[test]
+    fn rounds_and_clamps() {
+        assert_eq!(Color::from_rgb_f32(0.5, 0.2509804, 1.0).to_rgb8(), Rgb8::new(128, 64, 255));
+        assert_eq!(Color::from_rgb_f32(0.5, 0.0, 1.0).to_rgb16(), Rgb16::new(32768, 0, 65535));
+        assert_eq!(Rgba::from_rgba_f32(0.1, 0.2, 0.3, 0.5).to_rgba8(), Rgba8::new(26, 51, 77, 128));
+
+        for byte in 0..=255u8 {
+            let rgb = Rgb8::new(byte, byte, byte);
+            assert_eq!(Color::from(rgb).to_rgb8(), rgb);
+        }
+        let rgb = Rgb16::new(1, 32767, 65534);
+        assert_eq!(Color::from(rgb).to_rgb16(), rgb);
+    }
+
+    #[test]
+    fn palette_emits_integers() {
+        let palette = ColorPalette::from_seed(3, PaletteType::Oklch, Spread::Golden);
+        let expected: Vec<_> = palette.clone().take(5).map(|color| color.to_u8_array()).collect();
+        let bytes: Vec<_> = palette.into_rgb8().take(5).map(|rgb| rgb.to_array()).collect();
+        assert_eq!(bytes, expected);
+    }
+}
@@ -0,0 +1,143 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+use crate::{Color, ColorPalette, HueWalk, PaletteStrategy, PaletteType};
+
+/// The 64 bit FNV-1a hash. Unlike the hasher of `HashMap` its output is fully specified,
+/// so a key hashes to the same value in every process
+struct Fnv1a(u64);
+
+impl Hasher for Fnv1a {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x0100_0000_01B3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+impl ColorPalette {
+    /// The color for `key`, computed from a hash of the key alone. Needs no state at all, so every process
+    /// using a palette with the same settings (see `ColorPalette::from_seed`) gives a key the same color.
+    ///
+    /// The color is picked as if the palette walked with `HueWalk::Indexed`, so it takes no longer to compute
+    /// for one key than for another. Different keys can end up with similar or even the same color. Use `KeyedPalette` if keys need distinct colors.
+    /// The hash depends on the `Hash` implementation of the key. Strings and byte slices hash the same everywhere,
+    /// while integers hash differently on platforms of different endianness or pointer width
+    pub fn color_for<K: Hash + ?Sized>(&self, key: &K) -> Color {
+        let mut hasher = Fnv1a(0xCBF2_9CE4_8422_2325);
+        key.hash(&mut hasher);
+        let hash = hasher.finish();
+        let mut palette = self.clone();
+        palette.0.walk = HueWalk::Indexed;
+        // Fold to 32 bits so the index is the same on 32 bit platforms
+        palette.seek((hash ^ (hash >> 32)) as u32 as usize);
+        // The palette is infinite
+        palette.next().unwrap()
+    }
+}
+
+/// Assigns every distinct key the next color of a palette the first time it is seen,
+/// and the same color every time after that. Create one with `ColorPalette::keyed`.
+///
+/// ```rust
+/// use colourado_iter::{ColorPalette, KeyedPalette, PaletteType, Spread};
+///
+/// let mut hosts: KeyedPalette<String> = ColorPalette::from_seed(7, PaletteType::Oklch, Spread::Golden).keyed();
+/// let db = hosts.color_for("db-01");
+/// assert_ne!(hosts.color_for("web-01"), db);
+/// assert_eq!(hosts.color_for("db-01"), db);
+/// ```
+pub struct KeyedPalette<K, S = PaletteType> {
+    palette: ColorPalette<S>,
+    colors: HashMap<K, Color>,
+}
+
+impl<K: Hash + Eq, S: PaletteStrategy> KeyedPalette<K, S> {
+    pub fn new(palette: ColorPalette<S>) -> Self {
+        KeyedPalette {
+            palette,
+            colors: HashMap::new(),
+        }
+    }
+
+    /// The color of `key`, assigning it the next color of the palette if it has not been seen before
+    pub fn color_for<Q>(&mut self, key: &Q) -> Color
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ToOwned<Owned = K> + ?Sized,
+    {
+        if let Some(color) = self.colors.get(key) {
+            return *color;
+        }
+        // The palette is infinite
+        let color = self.palette.next().unwrap();
+        self.colors.insert(key.to_owned(), color);
+        color
+    }
+
+    /// The color of `key` if it has been assigned one
+    pub fn get<Q>(&self, key: &Q) -> Option<Color>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.colors.get(key).copied()
+    }
+
+    /// The number of keys which have been assigned a color
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+
+    pub fn get_inner(&self) -> &ColorPalette<S> {
+        &self.palette
+    }
+
+    pub fn into_inner(self) -> ColorPalette<S> {
+        self.palette
+    }
+}
+
+impl<S: PaletteStrategy> ColorPalette<S> {
+    /// Turn this palette into one which hands out a color per key, see `KeyedPalette`
+    pub fn keyed<K: Hash + Eq>(self) -> KeyedPalette<K, S> {
+        KeyedPalette::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KeyedPalette;
+    use crate::{ColorPalette, PaletteType, Spread};
+
+    #[test]
+    fn keeps_colors_per_key() {
+        let palette = ColorPalette::new(PaletteType::Random, false, &mut rand::thread_rng());
+        let expected: Vec<_> = palette.clone().take(2).collect();
+
+        let mut keyed: KeyedPalette<u32> = palette.keyed();
+        assert_eq!(keyed.color_for(&10), expected[0]);
+        assert_eq!(keyed.color_for(&20), expected[1]);
+        assert_eq!(keyed.color_for(&10), expected[0]);
+        assert_eq!(keyed.get(&30), None);
+        assert_eq!(keyed.len(), 2);
+    }
+
+    #[test]
+    fn hashes_keys_stably() {
+        let palette = ColorPalette::from_seed(1, PaletteType::Oklch, Spread::Golden);
+        assert_eq!(palette.color_for("db-01").to_hex(), "#CDAA3E");
+        assert_eq!(palette.color_for("db-01"), palette.color_for(&String::from("db-01")));
+        assert_ne!(palette.color_for("db-01"), palette.color_for("db-02"));
+    }
+}
@@ -1,6 +1,6 @@
 //! A small and minimalistic library to generate a random color palette.
 //! The user-facing `Color` struct contains RGB colors ranging from 0 to 1.
-//! All colors are of type f32 (no exceptions)
+//! All colors are of type f32, except for their `f64` twins `Color64` and `Hsv64`
 //! 
 //! # Usage
 //! 
@@ -18,46 +18,262 @@
 //! ```
 //! 
 //! The second param to ColorPalette::new() determines the color scheme.  
-//! Currently 3 different schemes are supported:  
+//! Currently 6 different schemes are supported:  
 //! `PaletteType::Random` generates random colors 
 //! `PaletteType::Pastel` generates pastel colors 
 //! `PaletteType::Dark` generates dark colors  
+//! `PaletteType::Oklch` generates colors of equal perceived lightness and colorfulness  
+//! `PaletteType::Harmony` generates colors following a color harmony rule such as `Harmony::Triadic`  
+//! `PaletteType::Monochrome` generates colors of a single hue  
 //! 
 //! The third param determines whether colors are generated close to each other
 //! or are spread apart. `true` generates adjacent colors while `false` will generate
 //! a very spread color palette.
+//! `ColorPalette::with_spread` takes a `Spread` instead, where `Spread::Golden` steps the hue by the
+//! golden angle and keeps new colors well separated no matter how many are generated.
 //!
-//! Optionally, you can use the `HsvPalette` struct to get a generator which spits out the immediate HSV values as opposed to a `Color` struct.
+//! Besides HSV, a `Color` converts to HSL, HWB and the perceptual CIE spaces `Xyz`, `Lab` and `Lch`.
+//! `Color` itself stores gamma encoded sRGB, use `Color::to_linear` to get linear light components.
+//!
+//! A single color can be turned into a scale with `Color::tints`, `Color::shades`, `Color::tones`
+//! and the perceptually even `Color::lightness_ramp`.
+//!
+//! Colors can also be parsed from hex strings and CSS style color functions:
+//! 
+//! ```rust
+//! use colourado_iter::Color;
+//! 
+//! let turquoise: Color = "rgb(64 224 207)".parse().unwrap();
+//! assert_eq!(turquoise, Color::from_hex("#40E0CF").unwrap());
+//! ```
+//!
+//! Use `ColorPalette::with_alpha` to generate `Rgba` colors with a fixed or varying alpha value,
+//! for example for overlapping chart series.
+//!
+//! To make sure no two colors look alike, `ColorPalette::min_distance` skips every color which is
+//! perceptually too close to one emitted before:
+//! 
+//! ```rust
+//! # #[cfg(feature = "alloc")] {
+//! use colourado_iter::{ColorPalette, DeltaE, PaletteType};
+//! 
+//! let palette = ColorPalette::new(PaletteType::Random, false, &mut rand::thread_rng());
+//! let series_colors: Vec<_> = palette.min_distance(10.0, DeltaE::Ciede2000).take(30).collect();
+//! # }
+//! ```
+//!
+//! `ColorPalette::from_color` generates a palette around a given color, for example a brand color,
+//! which is always emitted first.
+//!
+//! `ColorPalette::builder` configures a palette in more detail, for example to only generate warm hues
+//! or to keep saturation and value within a range. See `PaletteBuilder`.
+//!
+//! The built-in palette types all implement the `PaletteStrategy` trait. Implement it yourself and pass it to
+//! `ColorPalette::with_strategy` to generate your own color schemes.
+//!
+//! For categorical data, `FarthestPointPalette` picks every new color as far away as possible
+//! from all colors emitted before it.
+//!
+//! `HsvPalette::seek` and `HsvPalette::nth_color` move a palette to any color, and color number N is the same
+//! no matter how the palette got there. By default every hue steps on from the previous one, so seeking replays
+//! the colors before N. Build the palette with `PaletteBuilder::with_walk(HueWalk::Indexed)` to jump there directly.
+//!
+//! To give users, hosts or tags a color of their own, `ColorPalette::keyed` hands out the next color to every new key
+//! and remembers it, while `ColorPalette::color_for` derives the color from a hash of the key without any state.
+//!
+//! With the `serde` feature, `Color`, `PaletteType` and the palettes themselves implement `Serialize` and `Deserialize`,
+//! so the state of a palette can be saved and restored. A `Color` serializes as a hex string,
+//! use `serde_struct` to serialize its exact components instead.
+//!
+//! `HsvPalette::state` takes a `PaletteState` snapshot which `HsvPalette::from_state` resumes from,
+//! so a long running service can keep handing out new colors after a restart.
+//!
+//! For pipelines working in double precision, `Color64` and `Hsv64` are the `f64` twins of `Color` and `Hsv`
+//! and convert to and from them with `From`. `ColorPalette::into_f64` and `HsvPalette::into_f64`
+//! turn palettes into ones which emit `Color64` and `Hsv64`.
+//!
+//! `Rgb8`, `Rgb16`, `Rgba8` and `Rgba16` hold integer channels for image buffers, terminals and textures.
+//! Converting a `Color` clamps and rounds every component, and `ColorPalette::into_rgb8` emits `Rgb8` colors directly.
+//!
+//! # Stability
+//!
+//! `ColorPalette::from_seed` and `HsvPalette::from_seed` (as well as `PaletteBuilder::with_seed`) don't use an rng at all.
+//! For the same seed and settings they generate the same colors, bit for bit, on every platform with IEEE 754 floats.
+//! All float math used to generate them goes through `libm` rather than the platform math library.
+//! The colors are bit-identical for a given version of `libm`. The golden value tests of this crate
+//! check seeded palettes against every `libm` release it is tested with, but an update of `libm` in your
+//! dependency graph could in principle change the last bits of a color. Lock `libm` if you need certainty.
+//! Changing the colors of a seeded palette is a breaking change, so they stay the same across all releases with the same major version.
+//! Palettes created from an rng, like `ColorPalette::new`, carry no such guarantee since the rng itself may change.
+//!
+//! # `no_std`
+//!
+//! The crate works without `std` by disabling the default features.
+//! The `alloc` feature brings back everything which allocates, like `Color::to_hex`, `DistinctPalette`
+//! and `FarthestPointPalette`, while `KeyedPalette` needs `std`.
+//!
+//! Optionally, you can use the `HsvPalette` struct to get a generator which spits out the immediate `Hsv` values as opposed to a `Color` struct.
 //! 
 //! **WARNING** The `ColorPalette` iterator is infinite! It will never exhaust! As such, you should never
 //! use `collect` or `for x in` patterns with it. Instead, always use `take` if you want a certain number of colors. 
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 use rand::Rng;
 
 mod color;
 pub use color::Color;
 
+mod hsv;
+pub use hsv::Hsv;
+
+mod hsl;
+pub use hsl::Hsl;
+
+mod hwb;
+pub use hwb::Hwb;
+
+mod parse;
+pub use parse::ParseColorError;
+
+mod cie;
+pub use cie::{linear_to_srgb, srgb_to_linear, Lab, Lch, LinearRgb, Xyz};
+
+mod oklab;
+pub use oklab::{Oklab, Oklch};
+
+mod delta;
+pub use delta::DeltaE;
+
+#[cfg(feature = "alloc")]
+mod distinct;
+#[cfg(feature = "alloc")]
+pub use distinct::DistinctPalette;
+
+#[cfg(feature = "alloc")]
+mod farthest;
+#[cfg(feature = "alloc")]
+pub use farthest::FarthestPointPalette;
+
+mod harmony;
+pub use harmony::Harmony;
+
+mod scale;
+
+mod named;
+pub use named::CSS_COLORS;
+
+mod rgba;
+pub use rgba::{Alpha, Rgba, RgbaPalette};
+
+mod strategy;
+pub use strategy::{HueWalk, PaletteStrategy, Step};
+
+mod builder;
+pub use builder::{PaletteBuilder, Ranges};
+
+#[cfg(feature = "std")]
+mod keyed;
+#[cfg(feature = "std")]
+pub use keyed::KeyedPalette;
+
+#[cfg(feature = "serde")]
+mod serialize;
+#[cfg(feature = "serde")]
+pub use serialize::serde_struct;
+
+mod state;
+pub use state::PaletteState;
+
+mod double;
+pub use double::{Color64, ColorPalette64, Hsv64, HsvPalette64};
+
+mod integer;
+pub use integer::{IntegerPalette, Rgb16, Rgb8, Rgba16, Rgba8};
+
+mod math;
+mod precision;
+mod seed;
+mod seek;
+
 /// Container for a vector of colors.
 /// You can also use it to store your own custom palette of you so desire. 
-pub struct HsvPalette {
+/// 
+/// The colors are produced by a `PaletteStrategy`, which is one of the built-in `PaletteType`s by default.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HsvPalette<S = PaletteType> {
     iteration: usize,
     base_divergence: f32,
-    palette_type: PaletteType,
+    /// Scales the per color hue jitter added on top of `base_divergence`
+    jitter: f32,
+    palette_type: S,
+    /// The hue the palette walks along, see `Step::previous_hue`
     hue: Hue,
+    start_hue: Hue,
+    #[cfg_attr(feature = "serde", serde(default))]
+    walk: HueWalk,
+    ranges: Ranges,
+    /// A color to emit exactly as it is before the strategy takes over, both as `Hsv` and as `Color`
+    #[cfg_attr(feature = "serde", serde(with = "serialize::first_color"))]
+    first: Option<(Hsv, Color)>,
 }
 
-pub struct ColorPalette(HsvPalette);
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ColorPalette<S = PaletteType>(HsvPalette<S>);
 
+/// The built-in palettes
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PaletteType {
     Random,
     Pastel,
     Dark,
+    /// Steps the hue in Oklch at a fixed lightness and chroma, so every color carries the same perceived weight
+    Oklch,
+    /// Follows a harmony rule around the starting hue, so the palette looks intentional rather than merely distinct.
+    /// The first color is the starting hue itself. After every round through the rule the saturation and value change,
+    /// and after every few rounds the whole rule rotates a little. The spread of the palette is not used
+    Harmony(Harmony),
+    /// Stays on the starting hue and only varies saturation and value
+    Monochrome,
+}
+
+/// How far apart the hues of consecutive colors are
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Spread {
+    /// Steps of about 25°, generating colors close to each other
+    Adjacent,
+    /// Steps of about 80°, generating a very spread color palette
+    Wide,
+    /// Steps of exactly the golden angle (about 137.5°) without any jitter.
+    /// No hue is ever repeated and every new color lands in the largest gap left by the previous ones,
+    /// so colors stay well separated for arbitrarily long runs
+    Golden,
+}
+
+impl Spread {
+    /// The hue step in degrees and the jitter scale of this spread
+    fn divergence_and_jitter(&self) -> (f32, f32) {
+        match self {
+            Spread::Adjacent => (25.0, 1.0),
+            Spread::Wide => (80.0, 1.0),
+            Spread::Golden => (GOLDEN_ANGLE, 0.0),
+        }
+    }
 }
 
+/// The golden angle in degrees, 360° * (1 - 1 / φ)
+pub const GOLDEN_ANGLE: f32 = 137.50776;
+
 pub(crate) type Hue = f32;
 pub(crate) type Saturation = f32;
 pub(crate) type Value = f32;
-pub type Hsv = (Hue, Saturation, Value);
 
 impl ColorPalette {
     pub fn new<T: Rng>(palette_type: PaletteType, adjacent_colors: bool, rng: &mut T) -> Self {
@@ -68,123 +284,164 @@ impl ColorPalette {
         ))
     }
 
-    pub fn get_inner(&self) -> &HsvPalette {
+    pub fn with_spread<T: Rng>(palette_type: PaletteType, spread: Spread, rng: &mut T) -> Self {
+        ColorPalette(HsvPalette::with_spread(
+            palette_type,
+            spread,
+            rng
+        ))
+    }
+
+    /// Create a palette whose first color is exactly `color`, for example a brand color.
+    /// The following colors continue walking the color wheel from its hue
+    pub fn from_color(palette_type: PaletteType, color: Color, spread: Spread) -> Self {
+        PaletteBuilder::new()
+            .with_palette_type(palette_type)
+            .with_spread(spread)
+            .with_start_color(color)
+            .build_from_start()
+    }
+}
+
+impl<S: PaletteStrategy> ColorPalette<S> {
+    /// Create a palette driven by your own `PaletteStrategy`
+    pub fn with_strategy<T: Rng>(strategy: S, spread: Spread, rng: &mut T) -> Self {
+        ColorPalette(HsvPalette::with_strategy(
+            strategy,
+            spread,
+            rng
+        ))
+    }
+
+    pub fn get_inner(&self) -> &HsvPalette<S> {
         &self.0
     }
 
-    pub fn get_inner_mut(&mut self) -> &mut HsvPalette {
+    pub fn get_inner_mut(&mut self) -> &mut HsvPalette<S> {
         &mut self.0
     }
 
-    pub fn into_inner(self) -> HsvPalette {
+    pub fn into_inner(self) -> HsvPalette<S> {
         self.0
     }
 }
 
 impl HsvPalette {
     pub fn new<T: Rng>(palette_type: PaletteType, adjacent_colors: bool, rng: &mut T) -> Self {
+        let spread = if adjacent_colors {
+            Spread::Adjacent
+        } else {
+            Spread::Wide
+        };
+        Self::with_spread(palette_type, spread, rng)
+    }
 
-        let hue = rng.gen_range(0.0..360.0);
+    pub fn with_spread<T: Rng>(palette_type: PaletteType, spread: Spread, rng: &mut T) -> Self {
+        Self::with_strategy(palette_type, spread, rng)
+    }
 
-        let mut base_divergence = 80.0;
+    /// Create a palette whose first color is exactly `hsv`, for example a brand color.
+    /// The following colors continue walking the color wheel from its hue
+    pub fn from_hsv(palette_type: PaletteType, hsv: Hsv, spread: Spread) -> Self {
+        PaletteBuilder::new()
+            .with_palette_type(palette_type)
+            .with_spread(spread)
+            .with_start_hsv(hsv)
+            .build_from_start()
+            .into_inner()
+    }
+}
 
-        if adjacent_colors {
-            base_divergence = 25.0;
-        }
+impl<S: PaletteStrategy> HsvPalette<S> {
+    /// Create a palette driven by your own `PaletteStrategy`
+    pub fn with_strategy<T: Rng>(strategy: S, spread: Spread, rng: &mut T) -> Self {
+
+        let hue = rng.gen_range(0.0..360.0);
+
+        let (base_divergence, jitter) = spread.divergence_and_jitter();
+        let ranges = strategy.ranges();
 
         Self {
             base_divergence,
-            palette_type,
+            jitter,
+            palette_type: strategy,
             hue,
+            start_hue: hue,
+            walk: HueWalk::default(),
+            ranges,
+            first: None,
             iteration: 0
         }
     }
 
-
-    fn palette_dark(&self) -> Hsv {
-        let iteration = self.iteration as f32;
-        let f = (iteration * 43.0).cos().abs();
-        let mut div = self.base_divergence;
-
-        if div < 15.0 {
-            div = 15.0;
-        }
-
-        let hue = (self.hue + div + f).abs() % 360.0;
-        let saturation = 0.32 + ((iteration * 0.75).sin() / 2.0).abs();
-        let value = 0.1 + (iteration.cos() / 6.0).abs();
-        (hue, saturation, value)
+    /// The strategy which produces the colors of this palette
+    pub fn strategy(&self) -> &S {
+        &self.palette_type
     }
 
-    fn palette_pastel(&self) -> Hsv  {
-        let iteration = self.iteration as f32;
-        let f = (iteration * 25.0).cos().abs();
-        let mut div = self.base_divergence;
-
-        if div < 15.0 {
-            div = 15.0;
-        }
-
-        let hue = (self.hue + div + f).abs() % 360.0;
-        let saturation = ((iteration * 0.35).cos() / 5.0).abs();
-        let value = 0.5 + (iteration.cos() / 2.0).abs();
-        (hue, saturation, value)
+    /// Mutable access to the strategy, for example to change its settings between colors
+    pub fn strategy_mut(&mut self) -> &mut S {
+        &mut self.palette_type
     }
 
-    fn palette_random(&self) -> Hsv  {
-        let iteration = self.iteration as f32;
-        let f = (iteration * 55.0).tan().abs();
-        let mut div = self.base_divergence;
-
-        if div < 15.0 {
-            div = 15.0;
-        }
-
-        let hue = (self.hue + div + f).abs() % 360.0;
-        let mut saturation = (iteration * 0.35).sin().abs();
-        let mut value = ((6.33 * iteration) * 0.5).cos().abs();
-
-        if saturation < 0.4 {
-            saturation = 0.4;
+    /// What the strategy gets to know about the next color
+    fn step(&self) -> Step {
+        Step {
+            iteration: self.iteration,
+            start_hue: self.start_hue,
+            previous_hue: self.hue,
+            divergence: self.base_divergence,
+            jitter: self.jitter,
+            walk: self.walk,
         }
-
-        if value < 0.2 {
-            value = 0.2;
-        } else if value > 0.85 {
-            value = 0.85;
-        }
-        (hue, saturation, value)    
     }
+}
 
+impl<S: PaletteStrategy + Clone> HsvPalette<S> {
+    /// The next color, without advancing the palette
     pub fn get(&self) -> Hsv {
-        match self.palette_type {
-            PaletteType::Random => self.palette_random(),
-            PaletteType::Pastel => self.palette_pastel(),
-            PaletteType::Dark => self.palette_dark(),
+        if let (0, Some((hsv, _))) = (self.iteration, self.first) {
+            return hsv;
         }
+        self.ranges.apply(self.palette_type.clone().hsv(self.step()))
     }
 }
 
-impl Iterator for HsvPalette {
+impl<S: PaletteStrategy> Iterator for HsvPalette<S> {
     type Item = Hsv;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let (hue, saturation, value) = self.get();
-        self.hue = hue;
-        self.iteration += 1;
-        Some((hue, saturation, value))
+        if let (0, Some((hsv, _))) = (self.iteration, self.first) {
+            self.hue = hsv.hue();
+            self.iteration = self.iteration.saturating_add(1);
+            return Some(hsv);
+        }
+
+        let step = self.step();
+        let hsv = self.palette_type.hsv(step);
+        self.hue = self.palette_type.next_hue(&step, &hsv);
+        self.iteration = self.iteration.saturating_add(1);
+        Some(self.ranges.apply(hsv))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.seek(self.iteration.saturating_add(n));
+        self.next()
     }
 }
 
-impl Iterator for ColorPalette {
+impl<S: PaletteStrategy> Iterator for ColorPalette<S> {
     type Item = Color;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if let Some((hue, saturation, value)) = self.0.next() {
-            Some(Color::hsv_to_rgb(hue, saturation, value))
-        } else {
-            None
-        }
+        let first = self.0.first.filter(|_| self.0.iteration == 0).map(|(_, color)| color);
+        let hsv = self.0.next()?;
+        Some(first.unwrap_or_else(|| Color::from(hsv)))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.seek(self.0.iteration.saturating_add(n));
+        self.next()
     }
 }
 
@@ -213,4 +470,27 @@ mod tests {
             assert!(blue <= 1.0);
         }        
     }
+
+    #[test]
+    fn golden_spread_never_repeats() {
+        let palette = super::HsvPalette::with_spread(PaletteType::Pastel, super::Spread::Golden, &mut rand::thread_rng());
+        let mut hues: Vec<f32> = palette.take(100).map(|hsv| hsv.hue()).collect();
+        hues.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        // 100 golden angle steps leave gaps of at least 360° / 100 / φ²
+        for pair in hues.windows(2) {
+            assert!(pair[1] - pair[0] > 1.3);
+        }
+    }
+
+    #[test]
+    fn generates_even_oklch_palette() {
+        let palette = ColorPalette::new(PaletteType::Oklch, false, &mut rand::thread_rng());
+
+        for color in palette.take(20) {
+            let oklch = color.to_oklch();
+            assert!((oklch.l() - super::strategy::OKLCH_LIGHTNESS).abs() < 0.01);
+            assert!(oklch.chroma() <= super::strategy::OKLCH_CHROMA + 0.01);
+        }
+    }
 }
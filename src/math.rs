@@ -0,0 +1,73 @@
+//! Float functions which return the same bits on every platform and work without `std`.
+//! The methods on `f32` call into the math library of the platform, which is free to round differently,
+//! and are not available in `no_std` builds at all, so all float math goes through `libm` instead
+
+pub(crate) fn sin(x: f32) -> f32 {
+    libm::sinf(x)
+}
+
+pub(crate) fn cos(x: f32) -> f32 {
+    libm::cosf(x)
+}
+
+pub(crate) fn tan(x: f32) -> f32 {
+    libm::tanf(x)
+}
+
+pub(crate) fn atan2(y: f32, x: f32) -> f32 {
+    libm::atan2f(y, x)
+}
+
+pub(crate) fn hypot(x: f32, y: f32) -> f32 {
+    libm::hypotf(x, y)
+}
+
+pub(crate) fn sqrt(x: f32) -> f32 {
+    libm::sqrtf(x)
+}
+
+pub(crate) fn cbrt(x: f32) -> f32 {
+    libm::cbrtf(x)
+}
+
+pub(crate) fn exp(x: f32) -> f32 {
+    libm::expf(x)
+}
+
+pub(crate) fn powf(x: f32, y: f32) -> f32 {
+    libm::powf(x, y)
+}
+
+/// `x` multiplied by itself `n` times
+pub(crate) fn powi(x: f32, n: u32) -> f32 {
+    (0..n).fold(1.0, |power, _| power * x)
+}
+
+/// Round half way cases away from zero, like `f32::round`
+pub(crate) fn round(x: f32) -> f32 {
+    libm::roundf(x)
+}
+
+pub(crate) fn fract(x: f32) -> f32 {
+    x - libm::truncf(x)
+}
+
+/// The least non-negative remainder of `x / y`, like `f32::rem_euclid`
+pub(crate) fn rem_euclid(x: f32, y: f32) -> f32 {
+    let r = x % y;
+    if r < 0.0 {
+        r + y.abs()
+    } else {
+        r
+    }
+}
+
+/// The least non-negative remainder of `x / y`, like `f64::rem_euclid`
+pub(crate) fn rem_euclid_f64(x: f64, y: f64) -> f64 {
+    let r = x % y;
+    if r < 0.0 {
+        r + y.abs()
+    } else {
+        r
+    }
+}
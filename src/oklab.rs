@@ -0,0 +1,237 @@
+use crate::{Color, Hue, LinearRgb};
+use crate::precision::single::normalize_hue;
+use crate::math;
+
+/// A color in the Oklab perceptual color space, see https://bottosson.github.io/posts/oklab/.
+/// Lightness ranges from 0.0 to 1.0, `a` (green to red) and `b` (blue to yellow) stay roughly within -0.4 to 0.4
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Oklab {
+    l: f32,
+    a: f32,
+    b: f32,
+}
+
+impl Oklab {
+    pub fn new(l: f32, a: f32, b: f32) -> Self {
+        Oklab { l, a, b }
+    }
+
+    /// The perceptual lightness
+    pub fn l(&self) -> f32 {
+        self.l
+    }
+
+    pub fn a(&self) -> f32 {
+        self.a
+    }
+
+    pub fn b(&self) -> f32 {
+        self.b
+    }
+
+    /// Convert to a tuple of 3 floats
+    pub fn to_tuple(&self) -> (f32, f32, f32) {
+        (self.l, self.a, self.b)
+    }
+
+    /// Convert to linear RGB. The result may lie outside of the sRGB gamut
+    pub fn to_linear(&self) -> LinearRgb {
+        let l = self.l + 0.39633778 * self.a + 0.21580376 * self.b;
+        let m = self.l - 0.10556135 * self.a - 0.06385417 * self.b;
+        let s = self.l - 0.08948418 * self.a - 1.2914855 * self.b;
+
+        let (l, m, s) = (l * l * l, m * m * m, s * s * s);
+        LinearRgb::new(
+            4.0767417 * l - 3.3077116 * m + 0.23096993 * s,
+            -1.268438 * l + 2.6097574 * m - 0.3413194 * s,
+            -0.0041960863 * l - 0.7034186 * m + 1.7076147 * s,
+        )
+    }
+
+    /// Convert to an sRGB `Color`, clamping colors outside of the gamut
+    pub fn to_color(&self) -> Color {
+        self.to_linear().to_color()
+    }
+
+    /// Convert to the cylindrical Oklch form
+    pub fn to_oklch(&self) -> Oklch {
+        Oklch::new(self.l, math::hypot(self.a, self.b), math::atan2(self.b, self.a).to_degrees())
+    }
+}
+
+/// A color in Oklch color space, the cylindrical form of `Oklab`.
+/// The hue is kept in degrees within 0.0 - 360.0 and wraps around
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Oklch {
+    l: f32,
+    chroma: f32,
+    hue: Hue,
+}
+
+impl Oklch {
+    pub fn new(l: f32, chroma: f32, hue: Hue) -> Self {
+        Oklch {
+            l,
+            chroma,
+            hue: normalize_hue(hue),
+        }
+    }
+
+    /// The perceptual lightness
+    pub fn l(&self) -> f32 {
+        self.l
+    }
+
+    pub fn chroma(&self) -> f32 {
+        self.chroma
+    }
+
+    /// The hue in degrees, within 0.0 - 360.0
+    pub fn hue(&self) -> Hue {
+        self.hue
+    }
+
+    /// Convert to a tuple of 3 floats
+    pub fn to_tuple(&self) -> (f32, f32, Hue) {
+        (self.l, self.chroma, self.hue)
+    }
+
+    /// Convert to the rectangular Oklab form
+    pub fn to_oklab(&self) -> Oklab {
+        let hue = self.hue.to_radians();
+        Oklab::new(self.l, self.chroma * math::cos(hue), self.chroma * math::sin(hue))
+    }
+
+    /// Convert to an sRGB `Color`, clamping colors outside of the gamut
+    pub fn to_color(&self) -> Color {
+        self.to_oklab().to_color()
+    }
+
+    /// Convert to an sRGB `Color`, reducing the chroma until the color fits into the sRGB gamut.
+    /// Unlike `to_color` this keeps the lightness and hue intact
+    pub fn to_color_in_gamut(&self) -> Color {
+        let in_gamut = |chroma: f32| {
+            Oklch::new(self.l, chroma, self.hue)
+                .to_oklab()
+                .to_linear()
+                .to_array()
+                .iter()
+                .all(|x| (-0.00001..=1.00001).contains(x))
+        };
+        if in_gamut(self.chroma) {
+            return self.to_color();
+        }
+
+        let mut low = 0.0;
+        let mut high = self.chroma;
+        for _ in 0..24 {
+            let mid = (low + high) / 2.0;
+            if in_gamut(mid) {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        Oklch::new(self.l, low, self.hue).to_color()
+    }
+}
+
+impl LinearRgb {
+    /// Convert to Oklab
+    pub fn to_oklab(&self) -> Oklab {
+        let [r, g, b] = self.to_array();
+        let l = 0.41222147 * r + 0.53633254 * g + 0.051445993 * b;
+        let m = 0.2119035 * r + 0.6806995 * g + 0.10739696 * b;
+        let s = 0.08830246 * r + 0.28171884 * g + 0.6299787 * b;
+
+        let (l, m, s) = (math::cbrt(l), math::cbrt(m), math::cbrt(s));
+        Oklab::new(
+            0.21045426 * l + 0.7936178 * m - 0.004072047 * s,
+            1.9779985 * l - 2.4285922 * m + 0.4505937 * s,
+            0.025904037 * l + 0.78277177 * m - 0.80867577 * s,
+        )
+    }
+}
+
+impl Color {
+    /// Convert to Oklab
+    pub fn to_oklab(&self) -> Oklab {
+        self.to_linear().to_oklab()
+    }
+
+    /// Convert to Oklch
+    pub fn to_oklch(&self) -> Oklch {
+        self.to_oklab().to_oklch()
+    }
+}
+
+impl From<Color> for Oklab {
+    fn from(color: Color) -> Self {
+        color.to_oklab()
+    }
+}
+
+impl From<Oklab> for Color {
+    fn from(oklab: Oklab) -> Self {
+        oklab.to_color()
+    }
+}
+
+impl From<Color> for Oklch {
+    fn from(color: Color) -> Self {
+        color.to_oklch()
+    }
+}
+
+impl From<Oklch> for Color {
+    fn from(oklch: Oklch) -> Self {
+        oklch.to_color()
+    }
+}
+
+impl From<Oklab> for Oklch {
+    fn from(oklab: Oklab) -> Self {
+        oklab.to_oklch()
+    }
+}
+
+impl From<Oklch> for Oklab {
+    fn from(oklch: Oklch) -> Self {
+        oklch.to_oklab()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Oklch;
+    use crate::Color;
+    use float_cmp::assert_approx_eq;
+
+    #[test]
+    fn test_convert_oklab() {
+        let (l, a, b) = Color::from_rgb_u8(255, 255, 255).to_oklab().to_tuple();
+        assert_approx_eq!(f32, l, 1.0, epsilon = 0.0001);
+        assert_approx_eq!(f32, a, 0.0, epsilon = 0.0001);
+        assert_approx_eq!(f32, b, 0.0, epsilon = 0.0001);
+
+        let (l, chroma, hue) = Color::from_rgb_u8(255, 0, 0).to_oklch().to_tuple();
+        assert_approx_eq!(f32, l, 0.62796, epsilon = 0.0001);
+        assert_approx_eq!(f32, chroma, 0.25768, epsilon = 0.0001);
+        assert_approx_eq!(f32, hue, 29.2339, epsilon = 0.01);
+
+        for (_, bytes) in crate::CSS_COLORS {
+            let color = Color::from(*bytes);
+            assert_eq!(color.to_oklab().to_color().to_u8_array(), *bytes);
+            assert_eq!(color.to_oklch().to_color().to_u8_array(), *bytes);
+        }
+    }
+
+    #[test]
+    fn test_gamut_mapping() {
+        let vivid = Oklch::new(0.9, 0.3, 264.0);
+        let mapped = vivid.to_color_in_gamut().to_oklch();
+        assert_approx_eq!(f32, mapped.l(), 0.9, epsilon = 0.005);
+        assert_approx_eq!(f32, mapped.hue(), 264.0, epsilon = 1.0);
+        assert!(mapped.chroma() < 0.3);
+    }
+}
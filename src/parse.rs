@@ -0,0 +1,377 @@
+use core::fmt;
+use core::str::FromStr;
+
+use crate::math;
+use crate::{Color, Hsl, Hsv, Hwb, Rgba};
+
+/// The reasons a string can fail to parse as a `Color`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseColorError {
+    /// The input was empty or only contained whitespace
+    Empty,
+    /// A hex color did not have 3, 4, 6 or 8 digits. Contains the number of digits found
+    HexLength(usize),
+    /// A hex color contained a character which is not a hex digit
+    HexDigit(char),
+    /// The input was neither a hex color, a named color nor one of the supported color functions
+    UnknownFormat,
+    /// A color function was not closed with a parenthesis
+    UnclosedFunction,
+    /// A color function received the wrong number of components
+    ComponentCount { expected: usize, found: usize },
+    /// The component at this (zero based) position could not be parsed.
+    /// The alpha component, if any, is at position 3
+    InvalidComponent(usize),
+    /// Commas and spaces or slashes were mixed as separators
+    MixedSeparators,
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseColorError::Empty => write!(f, "empty color string"),
+            ParseColorError::HexLength(len) => write!(f, "hex color must have 3, 4, 6 or 8 digits, found {}", len),
+            ParseColorError::HexDigit(c) => write!(f, "invalid hex digit {:?}", c),
+            ParseColorError::UnknownFormat => write!(f, "unknown color format"),
+            ParseColorError::UnclosedFunction => write!(f, "color function is missing its closing parenthesis"),
+            ParseColorError::ComponentCount { expected, found } => write!(f, "expected {} color components, found {}", expected, found),
+            ParseColorError::InvalidComponent(index) => write!(f, "invalid color component at position {}", index),
+            ParseColorError::MixedSeparators => write!(f, "color components must be separated either by commas or by spaces"),
+        }
+    }
+}
+
+impl core::error::Error for ParseColorError {}
+
+/// The color functions understood by the parser
+#[derive(Copy, Clone)]
+enum Function {
+    Rgb,
+    Hsl,
+    Hsv,
+    Hwb,
+}
+
+impl Color {
+    /// Parse a hex color of the form `#RGB`, `#RGBA`, `#RRGGBB` or `#RRGGBBAA`.
+    /// The leading `#` is optional. An alpha component is validated but discarded.
+    pub fn from_hex(hex: &str) -> Result<Self, ParseColorError> {
+        parse_hex(hex).map(|(color, _)| color)
+    }
+}
+
+impl Rgba {
+    /// Parse a hex color of the form `#RGB`, `#RGBA`, `#RRGGBB` or `#RRGGBBAA`.
+    /// The leading `#` is optional. Colors without an alpha component are opaque.
+    pub fn from_hex(hex: &str) -> Result<Self, ParseColorError> {
+        parse_hex(hex).map(|(color, alpha)| Rgba::new(color, alpha.unwrap_or(1.0)))
+    }
+}
+
+/// Parses hex colors (`#RGB`, `#RGBA`, `#RRGGBB`, `#RRGGBBAA`), CSS named colors and the color functions
+/// `rgb()`, `rgba()`, `hsl()`, `hsla()`, `hsv()`, `hsva()` and `hwb()`.
+///
+/// Functions accept both the legacy comma separated syntax (`rgb(255, 0, 0)`) and the CSS Color Level 4
+/// space separated syntax with an optional alpha after a slash (`rgb(255 0 0 / 50%)`).
+/// RGB components are numbers from 0 to 255 or percentages. Hues are numbers in degrees or carry a
+/// `deg`, `rad`, `grad` or `turn` unit. Saturation, lightness and value are percentages, where a bare
+/// number is read as a percentage like CSS does. The keyword `none` is read as 0.
+/// Out of range components are clamped and the alpha component is validated but discarded.
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_color(s).map(|(color, _)| color)
+    }
+}
+
+/// Parses the same formats as `Color`, keeping the alpha component.
+/// Colors without an alpha component are opaque.
+impl FromStr for Rgba {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_color(s).map(|(color, alpha)| Rgba::new(color, alpha.unwrap_or(1.0)))
+    }
+}
+
+/// Parse a hex color into the color and its alpha component, if present
+fn parse_hex(hex: &str) -> Result<(Color, Option<f32>), ParseColorError> {
+    let hex = hex.trim();
+    let digits = hex.strip_prefix('#').unwrap_or(hex);
+
+    if digits.is_empty() {
+        return Err(ParseColorError::Empty);
+    }
+    if let Some(c) = digits.chars().find(|c| !c.is_ascii_hexdigit()) {
+        return Err(ParseColorError::HexDigit(c));
+    }
+
+    // All characters are ascii hex digits at this point, so indexing by byte is safe
+    let digit = |i: usize| u8::from_str_radix(&digits[i..i + 1], 16).unwrap();
+    let pair = |i: usize| u8::from_str_radix(&digits[i..i + 2], 16).unwrap();
+
+    match digits.len() {
+        3 => Ok((Color::from_rgb_u8(digit(0) * 17, digit(1) * 17, digit(2) * 17), None)),
+        4 => Ok((Color::from_rgb_u8(digit(0) * 17, digit(1) * 17, digit(2) * 17), Some((digit(3) * 17) as f32 / 255.0))),
+        6 => Ok((Color::from_rgb_u8(pair(0), pair(2), pair(4)), None)),
+        8 => Ok((Color::from_rgb_u8(pair(0), pair(2), pair(4)), Some(pair(6) as f32 / 255.0))),
+        len => Err(ParseColorError::HexLength(len)),
+    }
+}
+
+/// Parse any supported color string into the color and its alpha component, if present
+fn parse_color(s: &str) -> Result<(Color, Option<f32>), ParseColorError> {
+    let s = s.trim();
+
+    if s.is_empty() {
+        return Err(ParseColorError::Empty);
+    }
+    if s.starts_with('#') {
+        return parse_hex(s);
+    }
+
+    let open = match s.find('(') {
+        Some(open) => open,
+        None => return Color::from_name(s).map(|color| (color, None)).ok_or(ParseColorError::UnknownFormat),
+    };
+    let name = s[..open].trim();
+    let function = [
+        ("rgb", Function::Rgb),
+        ("hsl", Function::Hsl),
+        ("hsv", Function::Hsv),
+        ("hwb", Function::Hwb),
+    ]
+    .into_iter()
+    .find(|(function, _)| {
+        let name = strip_suffix_ignore_case(name, "a").unwrap_or(name);
+        name.eq_ignore_ascii_case(function)
+    })
+    .map(|(_, function)| function)
+    .ok_or(ParseColorError::UnknownFormat)?;
+    let args = s[open + 1..].strip_suffix(')').ok_or(ParseColorError::UnclosedFunction)?;
+    let (components, alpha) = split_components(args)?;
+
+    let color = match function {
+        Function::Rgb => parse_rgb_components(components)?,
+        Function::Hsl => Hsl::new(
+            parse_hue(components[0], 0)?,
+            parse_percentage(components[1], 1)?,
+            parse_percentage(components[2], 2)?,
+        ).to_color(),
+        Function::Hsv => Hsv::new(
+            parse_hue(components[0], 0)?,
+            parse_percentage(components[1], 1)?,
+            parse_percentage(components[2], 2)?,
+        ).to_color(),
+        Function::Hwb => Hwb::new(
+            parse_hue(components[0], 0)?,
+            parse_percentage(components[1], 1)?,
+            parse_percentage(components[2], 2)?,
+        ).to_color(),
+    };
+    Ok((color, alpha))
+}
+
+/// `s` without `suffix`, comparing ASCII letters case insensitively
+fn strip_suffix_ignore_case<'a>(s: &'a str, suffix: &str) -> Option<&'a str> {
+    let split = s.len().checked_sub(suffix.len())?;
+    if s.is_char_boundary(split) && s[split..].eq_ignore_ascii_case(suffix) {
+        Some(&s[..split])
+    } else {
+        None
+    }
+}
+
+/// Collect up to 4 parts without allocating, together with the total number of parts
+fn collect_parts<'a>(parts: impl Iterator<Item = &'a str>) -> ([&'a str; 4], usize) {
+    let mut collected = [""; 4];
+    let mut count = 0;
+    for part in parts {
+        if let Some(slot) = collected.get_mut(count) {
+            *slot = part;
+        }
+        count += 1;
+    }
+    (collected, count)
+}
+
+/// Split the arguments of a color function into its three components and the optional alpha
+fn split_components(args: &str) -> Result<([&str; 3], Option<f32>), ParseColorError> {
+    let (parts, mut count);
+    let alpha;
+
+    if args.contains(',') {
+        if args.contains('/') {
+            return Err(ParseColorError::MixedSeparators);
+        }
+        (parts, count) = collect_parts(args.split(',').map(str::trim));
+        if parts.iter().any(|part| part.contains(char::is_whitespace)) {
+            return Err(ParseColorError::MixedSeparators);
+        }
+        alpha = if count == 4 {
+            count -= 1;
+            Some(parts[3])
+        } else {
+            None
+        };
+    } else {
+        let (color, slash) = match args.split_once('/') {
+            Some((color, alpha)) => (color, Some(alpha.trim())),
+            None => (args, None),
+        };
+        (parts, count) = collect_parts(color.split_whitespace());
+        alpha = slash;
+    }
+
+    if count != 3 {
+        return Err(ParseColorError::ComponentCount { expected: 3, found: count + alpha.is_some() as usize });
+    }
+
+    let alpha = match alpha {
+        Some(alpha) => Some(parse_alpha(alpha, 3)?),
+        None => None,
+    };
+    Ok(([parts[0], parts[1], parts[2]], alpha))
+}
+
+/// Parse a plain number, reporting failures as the component at `index`
+fn parse_number(s: &str, index: usize) -> Result<f32, ParseColorError> {
+    if s.eq_ignore_ascii_case("none") {
+        return Ok(0.0);
+    }
+    match s.parse::<f32>() {
+        Ok(n) if n.is_finite() => Ok(n),
+        _ => Err(ParseColorError::InvalidComponent(index)),
+    }
+}
+
+/// Parse the three components of `rgb()`, which must either all be numbers or all be percentages.
+/// `none` goes with either
+fn parse_rgb_components(components: [&str; 3]) -> Result<Color, ParseColorError> {
+    let mut percentages = None;
+    let mut rgb = [0.0; 3];
+    for (index, (component, value)) in components.iter().zip(&mut rgb).enumerate() {
+        let is_percentage = component.ends_with('%');
+        if !component.eq_ignore_ascii_case("none") && *percentages.get_or_insert(is_percentage) != is_percentage {
+            return Err(ParseColorError::InvalidComponent(index));
+        }
+        *value = parse_rgb_component(component, index)?;
+    }
+    Ok(Color::from_array(rgb))
+}
+
+/// Parse an RGB component (0 to 255 or a percentage) into the 0.0 to 1.0 range
+fn parse_rgb_component(s: &str, index: usize) -> Result<f32, ParseColorError> {
+    match s.strip_suffix('%') {
+        Some(percent) => Ok(parse_number(percent, index)? / 100.0),
+        None => Ok(parse_number(s, index)? / 255.0),
+    }
+}
+
+/// Parse a percentage (with or without the `%` sign) into the 0.0 to 1.0 range
+fn parse_percentage(s: &str, index: usize) -> Result<f32, ParseColorError> {
+    let percent = parse_number(s.strip_suffix('%').unwrap_or(s), index)?;
+    Ok((percent / 100.0).clamp(0.0, 1.0))
+}
+
+/// Parse an alpha component (0.0 to 1.0 or a percentage)
+fn parse_alpha(s: &str, index: usize) -> Result<f32, ParseColorError> {
+    let alpha = match s.strip_suffix('%') {
+        Some(percent) => parse_number(percent, index)? / 100.0,
+        None => parse_number(s, index)?,
+    };
+    Ok(alpha.clamp(0.0, 1.0))
+}
+
+/// Parse a hue into degrees within 0.0 to 360.0
+fn parse_hue(s: &str, index: usize) -> Result<f32, ParseColorError> {
+    let units = [("deg", 1.0), ("grad", 0.9), ("rad", 180.0 / core::f32::consts::PI), ("turn", 360.0)];
+
+    let mut degrees = None;
+    for (unit, factor) in units {
+        if let Some(number) = strip_suffix_ignore_case(s, unit) {
+            degrees = Some(parse_number(number, index)? * factor);
+            break;
+        }
+    }
+    let degrees = match degrees {
+        Some(degrees) => degrees,
+        None => parse_number(s, index)?,
+    };
+    Ok(math::rem_euclid(degrees, 360.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ParseColorError;
+    use crate::{Color, Rgba};
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_parse_hex() {
+        let expected = Color::from_rgb_u8(0x40, 0xE0, 0xCF);
+        assert_eq!(Color::from_hex("#40E0CF"), Ok(expected));
+        assert_eq!(Color::from_hex("40e0cf"), Ok(expected));
+        assert_eq!("#40E0CF80".parse(), Ok(expected));
+        assert_eq!("#F0A".parse::<Color>().unwrap().to_hex(), "#FF00AA");
+
+        assert_eq!(Color::from_hex("#40E0C"), Err(ParseColorError::HexLength(5)));
+        assert_eq!(Color::from_hex("#40E0CG"), Err(ParseColorError::HexDigit('G')));
+        assert_eq!(Color::from_hex("#"), Err(ParseColorError::Empty));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_parse_functions() {
+        let cases = [
+            ("rgb(64, 224, 207)", "#40E0CF"),
+            ("rgba(64,224,207,0.5)", "#40E0CF"),
+            ("rgb(64 224 207 / 50%)", "#40E0CF"),
+            ("RGB(100% 0% 50%)", "#FF0080"),
+            ("hsl(120, 100%, 50%)", "#00FF00"),
+            ("hsl(0.5turn 100% 25%)", "#008080"),
+            ("hsla(360deg 100% 50% / 0.3)", "#FF0000"),
+            ("hsv(240, 100%, 100%)", "#0000FF"),
+            ("hsv(none 0% 50%)", "#808080"),
+            ("hwb(120 20% 40%)", "#339933"),
+            ("Turquoise", "#40E0D0"),
+        ];
+
+        for (input, hex) in cases {
+            assert_eq!(input.parse::<Color>().unwrap().to_hex(), hex, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_parse_alpha() {
+        let color = Color::from_rgb_u8(0x40, 0xE0, 0xCF);
+        assert_eq!(Rgba::from_hex("#40E0CF"), Ok(Rgba::new(color, 1.0)));
+        assert_eq!("#40E0CF33".parse(), Ok(Rgba::new(color, 0.2)));
+        assert_eq!("#FFF0".parse::<Rgba>().unwrap().alpha(), 0.0);
+        assert_eq!("rgba(64, 224, 207, 0.5)".parse(), Ok(Rgba::new(color, 0.5)));
+        assert_eq!("rgb(64 224 207 / 25%)".parse(), Ok(Rgba::new(color, 0.25)));
+        assert_eq!("turquoise".parse::<Rgba>().unwrap().alpha(), 1.0);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let cases = [
+            ("", ParseColorError::Empty),
+            ("notacolor", ParseColorError::UnknownFormat),
+            ("cmyk(0, 0, 0, 0)", ParseColorError::UnknownFormat),
+            ("rgb(0, 0, 0", ParseColorError::UnclosedFunction),
+            ("rgb(0, 0)", ParseColorError::ComponentCount { expected: 3, found: 2 }),
+            ("rgb(0 0 0 0)", ParseColorError::ComponentCount { expected: 3, found: 4 }),
+            ("rgb(0, x, 0)", ParseColorError::InvalidComponent(1)),
+            ("rgb(0 0 0 / y)", ParseColorError::InvalidComponent(3)),
+            ("rgb(10%, 20, 30)", ParseColorError::InvalidComponent(1)),
+            ("rgb(10 none 30%)", ParseColorError::InvalidComponent(2)),
+            ("rgb(0, 0, 0 / 1)", ParseColorError::MixedSeparators),
+        ];
+
+        for (input, error) in cases {
+            assert_eq!(input.parse::<Color>(), Err(error), "{}", input);
+        }
+    }
+}
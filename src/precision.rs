@@ -0,0 +1,83 @@
+//! The HSV math behind `Color` and `Hsv` and behind their `f64` twins `Color64` and `Hsv64`.
+//! Both precisions are generated from the same code, so they cannot drift apart
+
+macro_rules! hsv_math {
+    ($name:ident, $float:ty, $rem_euclid:path) => {
+        pub(crate) mod $name {
+            /// Clamp a component into the 0.0 to 1.0 range, mapping `NaN` to 0.0
+            pub(crate) fn clamp_unit(x: $float) -> $float {
+                if x.is_nan() {
+                    0.0
+                } else {
+                    x.clamp(0.0, 1.0)
+                }
+            }
+
+            /// Wrap a hue in degrees into 0.0 - 360.0, mapping `NaN` and infinities to 0.0
+            pub(crate) fn normalize_hue(hue: $float) -> $float {
+                if !hue.is_finite() {
+                    return 0.0;
+                }
+                let hue = $rem_euclid(hue, 360.0);
+                // rem_euclid can round up to exactly 360.0 for tiny negative hues
+                if hue >= 360.0 {
+                    0.0
+                } else {
+                    hue
+                }
+            }
+
+            /// Convert HSV to red, green and blue, which may still need clamping.
+            /// The hue wraps around the color wheel, saturation and value are clamped to 0.0 - 1.0
+            pub(crate) fn hsv_to_rgb(hue: $float, saturation: $float, value: $float) -> ($float, $float, $float) {
+                let saturation = clamp_unit(saturation);
+                let value = clamp_unit(value);
+                let chroma = value * saturation;
+                let hue2 = normalize_hue(hue) / 60.0;
+                let tmp = chroma * (1.0 - ((hue2 % 2.0) - 1.0).abs());
+
+                // hue2 lies within 0.0 - 6.0, so truncating is the same as flooring
+                // and the last sector also catches rounding at the very top
+                let color2 = match hue2 as u8 {
+                    0 => (chroma, tmp, 0.0),
+                    1 => (tmp, chroma, 0.0),
+                    2 => (0.0, chroma, tmp),
+                    3 => (0.0, tmp, chroma),
+                    4 => (tmp, 0.0, chroma),
+                    _ => (chroma, 0.0, tmp),
+                };
+
+                let m = value - chroma;
+                (color2.0 + m, color2.1 + m, color2.2 + m)
+            }
+
+            /// Convert red, green and blue to hue, saturation and value, where the hue may still need wrapping.
+            /// Grays have a hue of 0.0, and black also has a saturation of 0.0
+            pub(crate) fn rgb_to_hsv(r: $float, g: $float, b: $float) -> ($float, $float, $float) {
+                let cmax = r.max(g).max(b);
+                let cmin = r.min(g).min(b);
+                let delta = cmax - cmin;
+
+                let hue = if delta == 0.0 {
+                    0.0
+                } else if cmax == r {
+                    60.0 * ((g - b) / delta)
+                } else if cmax == g {
+                    60.0 * (((b - r) / delta) + 2.0)
+                } else {
+                    60.0 * (((r - g) / delta) + 4.0)
+                };
+
+                let saturation = if cmax == 0.0 {
+                    0.0
+                } else {
+                    delta / cmax
+                };
+                (hue, saturation, cmax)
+            }
+        }
+    };
+}
+
+hsv_math!(single, f32, crate::math::rem_euclid);
+hsv_math!(double, f64, crate::math::rem_euclid_f64);
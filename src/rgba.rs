@@ -0,0 +1,233 @@
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String};
+
+use crate::{Color, ColorPalette, PaletteStrategy, PaletteType};
+use crate::precision::single::clamp_unit;
+
+/// An RGB `Color` with an alpha component ranging from 0.0 (transparent) to 1.0 (opaque).
+/// The color components are stored straight, meaning they are not multiplied by the alpha value.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Rgba {
+    color: Color,
+    alpha: f32,
+}
+
+impl Rgba {
+    /// Create a new color from a `Color` and an alpha value (clamped to 0.0 - 1.0)
+    pub fn new(color: Color, alpha: f32) -> Self {
+        Rgba {
+            color,
+            alpha: clamp_unit(alpha),
+        }
+    }
+
+    /// Create a color from float components ranging from 0.0 to 1.0.
+    /// Values outside that range are clamped.
+    pub fn from_rgba_f32(red: f32, green: f32, blue: f32, alpha: f32) -> Self {
+        Rgba::new(Color::from_rgb_f32(red, green, blue), alpha)
+    }
+
+    /// Create a color from byte components ranging from 0 to 255
+    pub fn from_rgba_u8(red: u8, green: u8, blue: u8, alpha: u8) -> Self {
+        Rgba::new(Color::from_rgb_u8(red, green, blue), alpha as f32 / 255.0)
+    }
+
+    /// Create a color from premultiplied components, where red, green and blue have already been
+    /// multiplied by alpha. A fully transparent color becomes transparent black
+    pub fn from_premultiplied(rgba: [f32; 4]) -> Self {
+        let alpha = clamp_unit(rgba[3]);
+        if alpha == 0.0 {
+            return Rgba::from_rgba_f32(0.0, 0.0, 0.0, 0.0);
+        }
+        Rgba::from_rgba_f32(rgba[0] / alpha, rgba[1] / alpha, rgba[2] / alpha, alpha)
+    }
+
+    /// The color without its alpha component
+    pub fn color(&self) -> Color {
+        self.color
+    }
+
+    /// The alpha component
+    pub fn alpha(&self) -> f32 {
+        self.alpha
+    }
+
+    /// Return a copy with the alpha component replaced (clamped to 0.0 - 1.0)
+    pub fn with_alpha(self, alpha: f32) -> Self {
+        Rgba::new(self.color, alpha)
+    }
+
+    /// Convert to an array of 4 straight floats
+    pub fn to_array(&self) -> [f32; 4] {
+        let [red, green, blue] = self.color.to_array();
+        [red, green, blue, self.alpha]
+    }
+
+    /// Convert to an array of 4 floats where red, green and blue are multiplied by alpha
+    pub fn to_premultiplied(&self) -> [f32; 4] {
+        let [red, green, blue] = self.color.to_array();
+        [red * self.alpha, green * self.alpha, blue * self.alpha, self.alpha]
+    }
+
+    /// Convert to an array of 4 bytes, rounding each component to the nearest value
+    pub fn to_u8_array(&self) -> [u8; 4] {
+        self.to_rgba8().to_array()
+    }
+
+    /// Convert the color to a hex string of the form `#RRGGBBAA`
+    #[cfg(feature = "alloc")]
+    pub fn to_hex(&self) -> String {
+        let [red, green, blue, alpha] = self.to_u8_array();
+        format!("#{:02X}{:02X}{:02X}{:02X}", red, green, blue, alpha)
+    }
+
+    /// Composite this color over an opaque background, like a painter would.
+    /// Blending happens directly on the stored components, the same way browsers blend CSS colors
+    pub fn over(&self, background: Color) -> Color {
+        let [red, green, blue] = self.color.to_array();
+        let [bg_red, bg_green, bg_blue] = background.to_array();
+        let blend = |fg: f32, bg: f32| fg * self.alpha + bg * (1.0 - self.alpha);
+        Color::from_rgb_f32(blend(red, bg_red), blend(green, bg_green), blend(blue, bg_blue))
+    }
+
+    /// Composite this color over a possibly transparent background (Porter-Duff "source over")
+    pub fn over_rgba(&self, background: Rgba) -> Rgba {
+        let source = self.to_premultiplied();
+        let destination = background.to_premultiplied();
+        let blend = |i: usize| source[i] + destination[i] * (1.0 - self.alpha);
+        Rgba::from_premultiplied([blend(0), blend(1), blend(2), blend(3)])
+    }
+}
+
+impl From<Color> for Rgba {
+    fn from(color: Color) -> Self {
+        Rgba::new(color, 1.0)
+    }
+}
+
+impl From<[f32; 4]> for Rgba {
+    fn from(rgba: [f32; 4]) -> Self {
+        Rgba::from_rgba_f32(rgba[0], rgba[1], rgba[2], rgba[3])
+    }
+}
+
+impl From<Rgba> for [f32; 4] {
+    fn from(rgba: Rgba) -> Self {
+        rgba.to_array()
+    }
+}
+
+impl Color {
+    /// Attach an alpha value to this color
+    pub fn with_alpha(self, alpha: f32) -> Rgba {
+        Rgba::new(self, alpha)
+    }
+}
+
+/// How `RgbaPalette` assigns alpha values to the generated colors
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Alpha {
+    /// Every color gets the same alpha value
+    Fixed(f32),
+    /// The alpha value steps linearly from `start` to `end` over `steps` colors and then starts over
+    Ramp { start: f32, end: f32, steps: usize },
+}
+
+impl Alpha {
+    /// The alpha value for the color at `iteration`
+    fn at(&self, iteration: usize) -> f32 {
+        match *self {
+            Alpha::Fixed(alpha) => alpha,
+            Alpha::Ramp { start, steps, .. } if steps < 2 => start,
+            Alpha::Ramp { start, end, steps } => {
+                let t = (iteration % steps) as f32 / (steps - 1) as f32;
+                start + (end - start) * t
+            }
+        }
+    }
+}
+
+/// A `ColorPalette` which emits `Rgba` colors, for example to draw overlapping chart series.
+/// Just like `ColorPalette` this iterator is infinite.
+pub struct RgbaPalette<S = PaletteType> {
+    palette: ColorPalette<S>,
+    alpha: Alpha,
+    iteration: usize,
+}
+
+impl<S: PaletteStrategy> RgbaPalette<S> {
+    pub fn new(palette: ColorPalette<S>, alpha: Alpha) -> Self {
+        RgbaPalette {
+            palette,
+            alpha,
+            iteration: 0,
+        }
+    }
+
+    pub fn get_inner(&self) -> &ColorPalette<S> {
+        &self.palette
+    }
+
+    pub fn into_inner(self) -> ColorPalette<S> {
+        self.palette
+    }
+}
+
+impl<S: PaletteStrategy> ColorPalette<S> {
+    /// Turn this palette into one which emits `Rgba` colors with the given alpha values
+    pub fn with_alpha(self, alpha: Alpha) -> RgbaPalette<S> {
+        RgbaPalette::new(self, alpha)
+    }
+}
+
+impl<S: PaletteStrategy> Iterator for RgbaPalette<S> {
+    type Item = Rgba;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let color = self.palette.next()?;
+        let alpha = self.alpha.at(self.iteration);
+        self.iteration += 1;
+        Some(Rgba::new(color, alpha))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Alpha, Rgba};
+    use crate::{Color, ColorPalette, PaletteType};
+    use float_cmp::assert_approx_eq;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_premultiplied() {
+        let rgba = Rgba::from_rgba_f32(1.0, 0.5, 0.25, 0.5);
+        assert_eq!(rgba.to_premultiplied(), [0.5, 0.25, 0.125, 0.5]);
+        assert_eq!(Rgba::from_premultiplied(rgba.to_premultiplied()), rgba);
+        assert_eq!(Rgba::from_premultiplied([0.3, 0.3, 0.3, 0.0]), Rgba::from_rgba_f32(0.0, 0.0, 0.0, 0.0));
+        assert_eq!(Rgba::from_rgba_u8(0x40, 0xE0, 0xCF, 0x80).to_hex(), "#40E0CF80");
+    }
+
+    #[test]
+    fn test_over() {
+        let red = Color::from_rgb_f32(1.0, 0.0, 0.0).with_alpha(0.25);
+        let blue = Color::from_rgb_f32(0.0, 0.0, 1.0);
+        assert_eq!(red.over(blue), Color::from_rgb_f32(0.25, 0.0, 0.75));
+        assert_eq!(red.over_rgba(blue.into()), Rgba::from_rgba_f32(0.25, 0.0, 0.75, 1.0));
+
+        let composite = red.over_rgba(blue.with_alpha(0.5));
+        assert_approx_eq!(f32, composite.alpha(), 0.625);
+        assert_approx_eq!(f32, composite.color().red(), 0.4);
+        assert_approx_eq!(f32, composite.color().blue(), 0.6);
+    }
+
+    #[test]
+    fn test_palette_alpha() {
+        let palette = ColorPalette::new(PaletteType::Pastel, false, &mut rand::thread_rng());
+        let alphas: Vec<f32> = palette
+            .with_alpha(Alpha::Ramp { start: 1.0, end: 0.5, steps: 3 })
+            .take(4)
+            .map(|rgba| rgba.alpha())
+            .collect();
+        assert_eq!(alphas, [1.0, 0.75, 0.5, 1.0]);
+    }
+}
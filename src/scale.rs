@@ -0,0 +1,93 @@
+use crate::{Color, Oklch};
+
+/// Oklch lightness of the lightest and darkest step of `Color::lightness_ramp`
+const RAMP_LIGHTEST: f32 = 0.97;
+const RAMP_DARKEST: f32 = 0.25;
+
+/// The fraction of the way along a scale of `steps` colors, from 0.0 at the first to 1.0 at the last step
+fn fraction(step: usize, steps: usize) -> f32 {
+    if steps < 2 {
+        0.0
+    } else {
+        step as f32 / (steps - 1) as f32
+    }
+}
+
+impl Color {
+    /// Mix this color with `other`. A `ratio` of 0.0 returns this color, 1.0 returns `other`.
+    /// The components are interpolated directly, the same way CSS preprocessors mix colors
+    pub fn mix(&self, other: &Color, ratio: f32) -> Color {
+        let [red, green, blue] = self.to_array();
+        let [other_red, other_green, other_blue] = other.to_array();
+        let mix = |a: f32, b: f32| a + (b - a) * ratio;
+        Color::from_rgb_f32(mix(red, other_red), mix(green, other_green), mix(blue, other_blue))
+    }
+
+    /// A scale of `steps` tints, from this color to white
+    pub fn tints(&self, steps: usize) -> impl Iterator<Item = Color> {
+        self.scale_towards(Color::from_rgb_f32(1.0, 1.0, 1.0), steps)
+    }
+
+    /// A scale of `steps` shades, from this color to black
+    pub fn shades(&self, steps: usize) -> impl Iterator<Item = Color> {
+        self.scale_towards(Color::from_rgb_f32(0.0, 0.0, 0.0), steps)
+    }
+
+    /// A scale of `steps` tones, from this color to a medium gray
+    pub fn tones(&self, steps: usize) -> impl Iterator<Item = Color> {
+        self.scale_towards(Color::from_rgb_f32(0.5, 0.5, 0.5), steps)
+    }
+
+    /// A scale of `steps` colors with the hue and chroma of this color and evenly spaced perceptual lightness,
+    /// from almost white to dark. Useful for UI themes with 50 to 900 shade scales.
+    /// Lightness is spaced in Oklch, and the chroma is reduced where it does not fit into sRGB
+    pub fn lightness_ramp(&self, steps: usize) -> impl Iterator<Item = Color> {
+        let oklch = self.to_oklch();
+        (0..steps).map(move |step| {
+            let lightness = RAMP_LIGHTEST + (RAMP_DARKEST - RAMP_LIGHTEST) * fraction(step, steps);
+            Oklch::new(lightness, oklch.chroma(), oklch.hue()).to_color_in_gamut()
+        })
+    }
+
+    fn scale_towards(&self, target: Color, steps: usize) -> impl Iterator<Item = Color> {
+        let base = *self;
+        (0..steps).map(move |step| base.mix(&target, fraction(step, steps)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{HsvPalette, PaletteType};
+    #[cfg(feature = "alloc")]
+    use crate::Color;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_scales() {
+        let color = Color::from_rgb_u8(0x66, 0x33, 0x99);
+
+        let tints: Vec<_> = color.tints(3).map(|color| color.to_hex()).collect();
+        assert_eq!(tints, ["#663399", "#B399CC", "#FFFFFF"]);
+        let shades: Vec<_> = color.shades(3).map(|color| color.to_hex()).collect();
+        assert_eq!(shades, ["#663399", "#331A4D", "#000000"]);
+        assert_eq!(color.tones(2).last().unwrap().to_hex(), "#808080");
+        assert_eq!(color.tints(1).collect::<Vec<_>>(), [color]);
+        assert_eq!(color.tints(0).count(), 0);
+
+        let ramp: Vec<_> = color.lightness_ramp(10).map(|color| color.to_oklch().l()).collect();
+        for pair in ramp.windows(2) {
+            assert!((pair[0] - pair[1] - 0.08).abs() < 0.005);
+        }
+    }
+
+    #[test]
+    fn test_monochrome_palette() {
+        let mut palette = HsvPalette::new(PaletteType::Monochrome, false, &mut rand::thread_rng());
+        let first = palette.next().unwrap();
+
+        for hsv in palette.take(30) {
+            assert_eq!(hsv.hue(), first.hue());
+            assert_ne!((hsv.saturation(), hsv.value()), (first.saturation(), first.value()));
+        }
+    }
+}
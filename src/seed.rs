@@ -0,0 +1,59 @@
+use crate::{ColorPalette, HsvPalette, Hue, PaletteBuilder, PaletteType, Spread};
+
+/// One step of the SplitMix64 generator. It is tiny, fully specified and turns similar seeds into unrelated values,
+/// so the starting hue does not depend on how some version of `rand` happens to sample floats
+fn splitmix64(seed: u64) -> u64 {
+    let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// The starting hue for `seed`, within 0.0 - 360.0
+pub(crate) fn seed_hue(seed: u64) -> Hue {
+    // The top 24 bits fit exactly into an f32
+    (splitmix64(seed) >> 40) as f32 * (360.0 / (1u32 << 24) as f32)
+}
+
+impl ColorPalette {
+    /// Create a palette which generates the same colors for the same `seed` on every platform and in every release
+    /// of this crate with the same major version, for example for snapshot tests of rendered charts.
+    /// See the crate documentation for the exact stability guarantee
+    pub fn from_seed(seed: u64, palette_type: PaletteType, spread: Spread) -> Self {
+        PaletteBuilder::new()
+            .with_palette_type(palette_type)
+            .with_spread(spread)
+            .with_seed(seed)
+            .build_from_start()
+    }
+}
+
+impl HsvPalette {
+    /// Create a palette which generates the same colors for the same `seed`, see `ColorPalette::from_seed`
+    pub fn from_seed(seed: u64, palette_type: PaletteType, spread: Spread) -> Self {
+        ColorPalette::from_seed(seed, palette_type, spread).into_inner()
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use crate::{ColorPalette, Harmony, PaletteType, Spread};
+
+    /// The first colors of a seeded palette as hex strings
+    fn golden(seed: u64, palette_type: PaletteType, spread: Spread) -> Vec<String> {
+        ColorPalette::from_seed(seed, palette_type, spread)
+            .take(6)
+            .map(|color| color.to_hex())
+            .collect()
+    }
+
+    #[test]
+    fn seeded_palettes_are_stable() {
+        assert_eq!(golden(42, PaletteType::Random, Spread::Wide), ["#D98295", "#8DD982", "#4DBCD9", "#B11DD9", "#D91D03", "#58D903"]);
+        assert_eq!(golden(42, PaletteType::Pastel, Spread::Adjacent), ["#F9CCFF", "#C49FB9", "#B599A0", "#FEE9E4", "#D3D0CC", "#A3A49E"]);
+        assert_eq!(golden(7, PaletteType::Dark, Spread::Golden), ["#3C2E44", "#302E10", "#08242B", "#44142B", "#253520", "#110F26"]);
+        assert_eq!(golden(7, PaletteType::Oklch, Spread::Wide), ["#26C0E7", "#BB9AF4", "#F58A8B", "#C1B041", "#23C8B2", "#7EAEFF"]);
+        assert_eq!(golden(0, PaletteType::Harmony(Harmony::Triadic), Spread::Wide), ["#E639B2", "#B2E639", "#39B2E6", "#F285D2", "#D2F285", "#85D2F2"]);
+        assert_eq!(golden(u64::MAX, PaletteType::Monochrome, Spread::Wide), ["#9F447E", "#58364C", "#B791A9", "#6F1950", "#CE54A2", "#865073"]);
+    }
+}
@@ -0,0 +1,113 @@
+use crate::{Color, ColorPalette, Hsv, HsvPalette, HueWalk, PaletteStrategy};
+
+impl<S: PaletteStrategy> HsvPalette<S> {
+    /// The index of the color the next call to `next` returns, which is also the number of colors generated so far
+    pub fn position(&self) -> usize {
+        self.iteration
+    }
+
+    /// Move the palette to color number `index`, so that the next call to `next` returns it.
+    ///
+    /// Palettes walking with `HueWalk::Indexed` and driven by a seekable `PaletteStrategy`, which includes
+    /// all `PaletteType`s, jump there directly. Other palettes generate every color up to `index`,
+    /// starting over from their starting hue to move backwards.
+    /// A strategy which keeps state of its own is not reset in that case
+    pub fn seek(&mut self, index: usize) {
+        if self.walk == HueWalk::Indexed && self.palette_type.is_seekable() {
+            if index > 0 {
+                // Generate the color before `index` to know where the palette walks on from
+                self.iteration = index - 1;
+                self.next();
+            } else {
+                self.restart();
+            }
+            return;
+        }
+
+        if index < self.iteration {
+            self.restart();
+        }
+        while self.iteration < index {
+            self.next();
+        }
+    }
+
+    fn restart(&mut self) {
+        self.iteration = 0;
+        self.hue = self.start_hue;
+    }
+}
+
+impl<S: PaletteStrategy + Clone> HsvPalette<S> {
+    /// Color number `index` of this palette, without moving the palette. See `seek`
+    pub fn nth_color(&self, index: usize) -> Hsv {
+        let mut palette = self.clone();
+        palette.seek(index);
+        palette.get()
+    }
+}
+
+impl<S: PaletteStrategy> ColorPalette<S> {
+    /// The index of the color the next call to `next` returns, which is also the number of colors generated so far
+    pub fn position(&self) -> usize {
+        self.0.position()
+    }
+
+    /// Move the palette to color number `index`, see `HsvPalette::seek`
+    pub fn seek(&mut self, index: usize) {
+        self.0.seek(index)
+    }
+}
+
+impl<S: PaletteStrategy + Clone> ColorPalette<S> {
+    /// Color number `index` of this palette, without moving the palette. See `HsvPalette::seek`
+    pub fn nth_color(&self, index: usize) -> Color {
+        let mut palette = self.clone();
+        palette.seek(index);
+        palette.next().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ColorPalette, HsvPalette, Hsv64, HueWalk, PaletteType, Spread};
+
+    #[test]
+    fn seeks_to_any_color() {
+        for walk in [HueWalk::Accumulate, HueWalk::Indexed] {
+            for palette_type in [PaletteType::Random, PaletteType::Oklch, PaletteType::Monochrome] {
+                let mut palette = HsvPalette::builder()
+                    .with_palette_type(palette_type)
+                    .with_spread(Spread::Adjacent)
+                    .with_walk(walk)
+                    .build_hsv(&mut rand::thread_rng());
+                let colors: Vec<_> = palette.clone().take(100).collect();
+
+                assert_eq!(palette.nth_color(57), colors[57]);
+                assert_eq!(palette.nth(40), Some(colors[40]));
+                assert_eq!(palette.position(), 41);
+                palette.seek(3);
+                assert_eq!(palette.next(), Some(colors[3]));
+            }
+        }
+
+        let palette = ColorPalette::builder()
+            .with_palette_type(PaletteType::Pastel)
+            .with_seed(3)
+            .with_walk(HueWalk::Indexed)
+            .build(&mut rand::thread_rng());
+        let far = palette.nth_color(1_000_000_000);
+        assert_eq!(palette.nth_color(1_000_000_000), far);
+    }
+
+    #[test]
+    fn nth_saturates_at_the_end() {
+        let mut palette = ColorPalette::builder().with_walk(HueWalk::Indexed).build(&mut rand::thread_rng());
+        palette.next();
+        let last = palette.nth_color(usize::MAX);
+        let last_hsv = palette.get_inner().nth_color(usize::MAX);
+        assert_eq!(palette.clone().into_f64().nth(usize::MAX), Some(Hsv64::from(last_hsv).to_color()));
+        assert_eq!(palette.nth(usize::MAX), Some(last));
+        assert_eq!(palette.position(), usize::MAX);
+    }
+}
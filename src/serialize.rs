@@ -0,0 +1,238 @@
+use core::fmt;
+
+use serde::de::{self, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+
+use crate::{Color, Color64, Hsv, Hsv64, Hue, Saturation, Value};
+
+const FIELDS: &[&str] = &["red", "green", "blue"];
+
+/// The keys of a `Color` struct, in the order of `FIELDS`
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum Field {
+    Red,
+    Green,
+    Blue,
+}
+
+/// A `Color` serializes to a hex string like `"#40E0CF"`, which rounds the components to 8 bits.
+/// Use `serde_struct` to keep the exact components
+impl Serialize for Color {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // Same as `to_hex`, which needs `alloc`
+        let [red, green, blue] = self.to_u8_array();
+        serializer.collect_str(&format_args!("#{:02X}{:02X}{:02X}", red, green, blue))
+    }
+}
+
+/// A `Color` deserializes from any color string `Color::from_str` understands. Human readable formats
+/// like JSON also accept a struct of `red`, `green` and `blue` or an array of 3 floats ranging from 0.0 to 1.0,
+/// while binary formats like bincode or postcard only read back the string `Serialize` writes
+impl<'de> Deserialize<'de> for Color {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(ColorVisitor)
+        } else {
+            deserializer.deserialize_str(ColorVisitor)
+        }
+    }
+}
+
+struct ColorVisitor;
+
+impl<'de> Visitor<'de> for ColorVisitor {
+    type Value = Color;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a color string, a struct of red, green and blue or an array of 3 floats")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Color, E> {
+        value.parse().map_err(E::custom)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Color, A::Error> {
+        let mut rgb = [0.0; 3];
+        for (i, component) in rgb.iter_mut().enumerate() {
+            *component = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(i, &self))?;
+        }
+        Ok(Color::from_array(rgb))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Color, A::Error> {
+        let mut rgb = [None; 3];
+        while let Some(key) = map.next_key::<Field>()? {
+            let i = key as usize;
+            if rgb[i].is_some() {
+                return Err(de::Error::duplicate_field(FIELDS[i]));
+            }
+            rgb[i] = Some(map.next_value()?);
+        }
+
+        let mut components = [0.0; 3];
+        for (i, component) in components.iter_mut().enumerate() {
+            *component = rgb[i].ok_or_else(|| de::Error::missing_field(FIELDS[i]))?;
+        }
+        Ok(Color::from_array(components))
+    }
+}
+
+/// Serialize a `Color` as a struct of its exact `red`, `green` and `blue` components instead of a hex string:
+///
+/// ```rust
+/// use colourado_iter::Color;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Theme {
+///     #[serde(with = "colourado_iter::serde_struct")]
+///     accent: Color,
+/// }
+/// ```
+pub mod serde_struct {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(color: &Color, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Color", 3)?;
+        state.serialize_field("red", &color.red())?;
+        state.serialize_field("green", &color.green())?;
+        state.serialize_field("blue", &color.blue())?;
+        state.end()
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+        deserializer.deserialize_struct("Color", FIELDS, ColorVisitor)
+    }
+}
+
+/// The fields of an `Hsv`, which deserializes through `Hsv::new` so its hue is wrapped and the rest clamped
+#[derive(Deserialize)]
+pub(crate) struct HsvFields {
+    hue: Hue,
+    saturation: Saturation,
+    value: Value,
+}
+
+impl From<HsvFields> for Hsv {
+    fn from(hsv: HsvFields) -> Self {
+        Hsv::new(hsv.hue, hsv.saturation, hsv.value)
+    }
+}
+
+/// The fields of a `Color64`, which deserializes through `Color64::from_rgb_f64` so its components are clamped
+#[derive(Deserialize)]
+pub(crate) struct Color64Fields {
+    red: f64,
+    green: f64,
+    blue: f64,
+}
+
+impl From<Color64Fields> for Color64 {
+    fn from(color: Color64Fields) -> Self {
+        Color64::from_rgb_f64(color.red, color.green, color.blue)
+    }
+}
+
+/// The fields of an `Hsv64`, which deserializes through `Hsv64::new` like `Hsv`
+#[derive(Deserialize)]
+pub(crate) struct Hsv64Fields {
+    hue: f64,
+    saturation: f64,
+    value: f64,
+}
+
+impl From<Hsv64Fields> for Hsv64 {
+    fn from(hsv: Hsv64Fields) -> Self {
+        Hsv64::new(hsv.hue, hsv.saturation, hsv.value)
+    }
+}
+
+/// A `Color` in struct form, so palettes keep their exact starting color
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+struct ExactColor(#[serde(with = "serde_struct")] Color);
+
+/// (De)serializes the starting color of a palette, see `HsvPalette`
+pub(crate) mod first_color {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(first: &Option<(Hsv, Color)>, serializer: S) -> Result<S::Ok, S::Error> {
+        first.map(|(hsv, color)| (hsv, ExactColor(color))).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<(Hsv, Color)>, D::Error> {
+        let first = Option::<(Hsv, ExactColor)>::deserialize(deserializer)?;
+        Ok(first.map(|(hsv, color)| (hsv, color.0)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Color, Color64, ColorPalette, Hsv, Hsv64, PaletteType, Spread};
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn color_formats() {
+        let color = Color::from_rgb_u8(0x40, 0xE0, 0xCF);
+        assert_eq!(serde_json::to_string(&color).unwrap(), "\"#40E0CF\"");
+
+        for json in ["\"#40E0CF\"", "\"rgb(64 224 207)\"", "[0.2509804, 0.8784314, 0.8117647]"] {
+            let parsed: Color = serde_json::from_str(json).unwrap();
+            assert_eq!(parsed.to_hex(), "#40E0CF");
+        }
+
+        let exact = Color::from_rgb_f32(0.1, 0.25, 0.3);
+        let mut json = Vec::new();
+        super::serde_struct::serialize(&exact, &mut serde_json::Serializer::new(&mut json)).unwrap();
+        assert_eq!(String::from_utf8(json.clone()).unwrap(), r#"{"red":0.1,"green":0.25,"blue":0.3}"#);
+        assert_eq!(serde_json::from_slice::<Color>(&json).unwrap(), exact);
+        assert!(serde_json::from_str::<Color>(r#"{"red":0.1,"green":0.25}"#).is_err());
+    }
+
+    #[test]
+    fn sanitises_input() {
+        let hsv: Hsv = serde_json::from_str(r#"{"hue":720,"saturation":5,"value":-3}"#).unwrap();
+        assert_eq!(hsv, Hsv::new(720.0, 5.0, -3.0));
+        let hsv: Hsv64 = serde_json::from_str(r#"{"hue":720,"saturation":5,"value":-3}"#).unwrap();
+        assert_eq!(hsv, Hsv64::new(720.0, 5.0, -3.0));
+
+        let color: Color64 = serde_json::from_str(r#"{"red":7.0,"green":-1.0,"blue":0.5}"#).unwrap();
+        assert_eq!(color, Color64::from_rgb_f64(1.0, 0.0, 0.5));
+        assert_eq!(serde_json::to_string(&color).unwrap(), r#"{"red":1.0,"green":0.0,"blue":0.5}"#);
+
+        let palette = ColorPalette::from_color(PaletteType::Pastel, Color::from_rgb_f32(0.1, 0.25, 0.3), Spread::Wide);
+        let mut json = serde_json::to_value(palette.into_inner()).unwrap();
+        json["ranges"]["saturation"] = serde_json::json!({"start": 0.9, "end": 0.6});
+        json["first"][0] = serde_json::json!({"hue": -90.0, "saturation": 2.0, "value": 0.5});
+        json["first"][1] = serde_json::json!({"red": 7.0, "green": -1.0, "blue": 0.5});
+
+        let mut restored: ColorPalette = serde_json::from_value(json).unwrap();
+        assert_eq!(restored.get_inner().get(), Hsv::new(270.0, 1.0, 0.5));
+        assert_eq!(restored.next(), Some(Color::from_rgb_f32(1.0, 0.0, 0.5)));
+        for hsv in restored.into_inner().take(20) {
+            assert!((0.6..=0.9).contains(&hsv.saturation()));
+        }
+    }
+
+    #[test]
+    fn binary_round_trip() {
+        let color = Color::from_rgb_u8(0x40, 0xE0, 0xCF);
+        let bytes = bincode::serialize(&color).unwrap();
+        assert_eq!(bincode::deserialize::<Color>(&bytes).unwrap(), color);
+
+        let mut palette = ColorPalette::from_color(PaletteType::Dark, color, Spread::Golden);
+        let bytes = bincode::serialize(&palette).unwrap();
+        let mut restored: ColorPalette = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(restored.by_ref().take(5).collect::<Vec<_>>(), palette.by_ref().take(5).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn palette_round_trip() {
+        let mut palette = ColorPalette::from_color(PaletteType::Oklch, Color::from_rgb_f32(0.1, 0.25, 0.3), Spread::Wide);
+        let json = serde_json::to_string(&palette).unwrap();
+        let mut restored: ColorPalette = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.by_ref().take(10).collect::<Vec<_>>(), palette.by_ref().take(10).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,138 @@
+use crate::{Color, ColorPalette, Hsv, HsvPalette, Hue, HueWalk, PaletteStrategy, PaletteType, Ranges};
+
+/// A snapshot of everything a palette needs to continue where it left off, for example to hand out
+/// the next color after a service restarts. Take one with `HsvPalette::state` and resume with `HsvPalette::from_state`.
+///
+/// Palettes only draw from an rng once to pick their starting hue, so there is no rng state to save.
+/// A `PaletteState` can only be created from a palette, so resuming in another process, for example after
+/// a restart, needs the `serde` feature to store the state and read it back.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PaletteState<S = PaletteType> {
+    iteration: usize,
+    hue: Hue,
+    start_hue: Hue,
+    divergence: f32,
+    jitter: f32,
+    #[cfg_attr(feature = "serde", serde(default))]
+    walk: HueWalk,
+    strategy: S,
+    ranges: Ranges,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialize::first_color"))]
+    first: Option<(Hsv, Color)>,
+}
+
+impl<S> PaletteState<S> {
+    /// The index of the next color the palette generates
+    pub fn position(&self) -> usize {
+        self.iteration
+    }
+
+    /// The hue the palette walks on from, see `Step::previous_hue`
+    pub fn hue(&self) -> Hue {
+        self.hue
+    }
+
+    /// The starting hue of the palette
+    pub fn start_hue(&self) -> Hue {
+        self.start_hue
+    }
+
+    /// The hue step in degrees between consecutive colors
+    pub fn divergence(&self) -> f32 {
+        self.divergence
+    }
+
+    /// The jitter scale, see `PaletteBuilder::with_jitter`
+    pub fn jitter(&self) -> f32 {
+        self.jitter
+    }
+
+    /// How the palette walks the color wheel
+    pub fn walk(&self) -> HueWalk {
+        self.walk
+    }
+
+    /// The ranges the palette keeps its colors in
+    pub fn ranges(&self) -> &Ranges {
+        &self.ranges
+    }
+
+    /// The color the palette emits exactly as it is before its strategy takes over, if it has one
+    pub fn start_color(&self) -> Option<Color> {
+        self.first.map(|(_, color)| color)
+    }
+
+    /// The strategy generating the colors, which is the `PaletteType` for the built-in palettes
+    pub fn strategy(&self) -> &S {
+        &self.strategy
+    }
+}
+
+impl<S: PaletteStrategy + Clone> HsvPalette<S> {
+    /// A snapshot of this palette, see `PaletteState`
+    pub fn state(&self) -> PaletteState<S> {
+        PaletteState {
+            iteration: self.iteration,
+            hue: self.hue,
+            start_hue: self.start_hue,
+            divergence: self.base_divergence,
+            jitter: self.jitter,
+            walk: self.walk,
+            strategy: self.palette_type.clone(),
+            ranges: self.ranges.clone(),
+            first: self.first,
+        }
+    }
+}
+
+impl<S: PaletteStrategy> HsvPalette<S> {
+    /// Resume a palette from a snapshot taken with `state`
+    pub fn from_state(state: PaletteState<S>) -> Self {
+        HsvPalette {
+            iteration: state.iteration,
+            base_divergence: state.divergence,
+            jitter: state.jitter,
+            palette_type: state.strategy,
+            hue: state.hue,
+            start_hue: state.start_hue,
+            walk: state.walk,
+            ranges: state.ranges,
+            first: state.first,
+        }
+    }
+}
+
+impl<S: PaletteStrategy + Clone> ColorPalette<S> {
+    /// A snapshot of this palette, see `PaletteState`
+    pub fn state(&self) -> PaletteState<S> {
+        self.0.state()
+    }
+}
+
+impl<S: PaletteStrategy> ColorPalette<S> {
+    /// Resume a palette from a snapshot taken with `state`
+    pub fn from_state(state: PaletteState<S>) -> Self {
+        ColorPalette(HsvPalette::from_state(state))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ColorPalette, PaletteType};
+
+    #[test]
+    fn resumes_from_state() {
+        let mut palette = ColorPalette::new(PaletteType::Pastel, true, &mut rand::thread_rng());
+        palette.nth(4);
+
+        let state = palette.state();
+        assert_eq!(state.position(), 5);
+        assert_eq!(state.divergence(), 25.0);
+        assert_eq!(state.start_color(), None);
+        assert_eq!(state.ranges(), &crate::Ranges::default());
+
+        let resumed: Vec<_> = ColorPalette::from_state(state).take(10).collect();
+        assert_eq!(resumed, palette.take(10).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,293 @@
+use crate::math;
+use crate::{Harmony, Hsv, Hue, Oklch, PaletteType, Ranges, Saturation, Value};
+
+/// Everything a `PaletteStrategy` gets to know about the color it should produce
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct Step {
+    /// The index of the color, starting at 0
+    pub iteration: usize,
+    /// The starting hue of the palette
+    pub start_hue: Hue,
+    /// The hue the palette walks along. For the first color this is the starting hue of the palette,
+    /// afterwards it is whatever `PaletteStrategy::next_hue` returned for the previous color
+    pub previous_hue: Hue,
+    /// The hue step in degrees configured on the palette, for example by its `Spread`
+    pub divergence: f32,
+    /// How much irregularity to add on top of `divergence`, where 1.0 is the default amount and 0.0 means none
+    pub jitter: f32,
+    /// How the palette walks the color wheel, see `HueWalk`
+    pub walk: HueWalk,
+}
+
+/// How the built-in palette types step from one hue to the next
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HueWalk {
+    /// Every hue steps on from the previous one, so the jitter of one color carries over to all colors after it.
+    /// The first color lies one step past the starting hue, unless the palette starts with a given color.
+    /// Reaching color number N means generating all colors before it
+    #[default]
+    Accumulate,
+    /// Color number N lies exactly N steps away from the starting hue, plus a jitter of its own.
+    /// Every color can be computed from its index alone, so palettes seek to any color directly,
+    /// see `HsvPalette::seek`. The colors differ from the ones `HueWalk::Accumulate` generates
+    Indexed,
+}
+
+/// Decides which colors an `HsvPalette` (and with it a `ColorPalette`) generates.
+///
+/// `PaletteType` implements this trait for the built-in palettes. Implement it yourself to drive a palette
+/// with your own color scheme:
+///
+/// ```rust
+/// use colourado_iter::{ColorPalette, Hsv, PaletteStrategy, Spread, Step};
+///
+/// /// Alternates between bright and dull colors
+/// struct Neon;
+///
+/// impl PaletteStrategy for Neon {
+///     fn hsv(&mut self, step: Step) -> Hsv {
+///         let value = if step.iteration % 2 == 0 { 1.0 } else { 0.6 };
+///         Hsv::new(step.previous_hue + step.divergence, 1.0, value)
+///     }
+/// }
+///
+/// let palette = ColorPalette::with_strategy(Neon, Spread::Wide, &mut rand::thread_rng());
+/// let colors: Vec<_> = palette.take(5).collect();
+/// ```
+pub trait PaletteStrategy {
+    /// Produce the color for `step`
+    fn hsv(&mut self, step: Step) -> Hsv;
+
+    /// The hue the palette continues walking from once `hsv` has been generated for `step`.
+    /// Defaults to the HSV hue of the color. Override this if your strategy walks along a different hue,
+    /// for example along the Oklch hue
+    fn next_hue(&self, step: &Step, hsv: &Hsv) -> Hue {
+        let _ = step;
+        hsv.hue()
+    }
+
+    /// The ranges the palette keeps the colors in, unless they were configured with a `PaletteBuilder`.
+    /// Defaults to the whole color wheel with any saturation and value
+    fn ranges(&self) -> Ranges {
+        Ranges::default()
+    }
+
+    /// Whether the color for a step only depends on `Step::iteration` and the settings of the palette,
+    /// but neither on `Step::previous_hue` nor on the colors generated before, as long as the palette walks
+    /// with `HueWalk::Indexed`. Palettes can then jump to any color directly instead of generating every
+    /// color before it, see `HsvPalette::seek`. Defaults to `false`
+    fn is_seekable(&self) -> bool {
+        false
+    }
+}
+
+impl<S: PaletteStrategy + ?Sized> PaletteStrategy for &mut S {
+    fn hsv(&mut self, step: Step) -> Hsv {
+        (**self).hsv(step)
+    }
+
+    fn next_hue(&self, step: &Step, hsv: &Hsv) -> Hue {
+        (**self).next_hue(step, hsv)
+    }
+
+    fn ranges(&self) -> Ranges {
+        (**self).ranges()
+    }
+
+    fn is_seekable(&self) -> bool {
+        (**self).is_seekable()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S: PaletteStrategy + ?Sized> PaletteStrategy for alloc::boxed::Box<S> {
+    fn hsv(&mut self, step: Step) -> Hsv {
+        (**self).hsv(step)
+    }
+
+    fn next_hue(&self, step: &Step, hsv: &Hsv) -> Hue {
+        (**self).next_hue(step, hsv)
+    }
+
+    fn ranges(&self) -> Ranges {
+        (**self).ranges()
+    }
+
+    fn is_seekable(&self) -> bool {
+        (**self).is_seekable()
+    }
+}
+
+/// Saturation and value of consecutive rounds through a `PaletteType::Harmony` rule
+const HARMONY_VARIANTS: [(Saturation, Value); 3] = [(0.75, 0.9), (0.45, 0.95), (0.85, 0.55)];
+/// Hue rotation in degrees applied to a `PaletteType::Harmony` rule after all variants were used
+const HARMONY_ROTATION: Hue = 15.0;
+
+/// Steps of the R2 low discrepancy sequence, which `PaletteType::Monochrome` uses
+/// to spread saturation and value evenly without repeating
+const MONOCHROME_STEPS: (f32, f32) = (0.754_877_7, 0.569_840_3);
+
+/// Lightness and chroma used by `PaletteType::Oklch`
+pub(crate) const OKLCH_LIGHTNESS: f32 = 0.75;
+pub(crate) const OKLCH_CHROMA: f32 = 0.13;
+
+impl PaletteStrategy for PaletteType {
+    fn hsv(&mut self, step: Step) -> Hsv {
+        match *self {
+            PaletteType::Random => palette_random(&step),
+            PaletteType::Pastel => palette_pastel(&step),
+            PaletteType::Dark => palette_dark(&step),
+            PaletteType::Oklch => palette_oklch(&step),
+            PaletteType::Harmony(harmony) => palette_harmony(&step, harmony),
+            PaletteType::Monochrome => palette_monochrome(&step),
+        }
+    }
+
+    fn next_hue(&self, step: &Step, hsv: &Hsv) -> Hue {
+        match self {
+            PaletteType::Oklch => oklch_hue(step),
+            _ => hsv.hue(),
+        }
+    }
+
+    fn ranges(&self) -> Ranges {
+        match self {
+            PaletteType::Random => Ranges {
+                saturation: 0.4..=1.0,
+                value: 0.2..=0.85,
+                ..Ranges::default()
+            },
+            _ => Ranges::default(),
+        }
+    }
+
+    fn is_seekable(&self) -> bool {
+        true
+    }
+}
+
+/// The hue `steps` steps of `divergence` degrees away from the starting hue.
+/// This is computed in f64 so it stays accurate no matter how far into the palette `steps` is
+fn walk(step: &Step, steps: usize) -> Hue {
+    math::rem_euclid_f64(step.start_hue as f64 + steps as f64 * step.divergence as f64, 360.0) as Hue
+}
+
+/// The hue for `step` with `jitter` degrees added on top of the divergence, see `HueWalk`
+fn jittered_hue(step: &Step, jitter: f32) -> Hue {
+    match step.walk {
+        HueWalk::Accumulate => (step.previous_hue + step.divergence + jitter).abs() % 360.0,
+        HueWalk::Indexed => walk(step, step.iteration) + jitter,
+    }
+}
+
+fn palette_dark(step: &Step) -> Hsv {
+    let iteration = step.iteration as f32;
+    let f = math::cos(iteration * 43.0).abs() * step.jitter;
+    let hue = jittered_hue(step, f);
+    let saturation = 0.32 + (math::sin(iteration * 0.75) / 2.0).abs();
+    let value = 0.1 + (math::cos(iteration) / 6.0).abs();
+    Hsv::new(hue, saturation, value)
+}
+
+fn palette_pastel(step: &Step) -> Hsv {
+    let iteration = step.iteration as f32;
+    let f = math::cos(iteration * 25.0).abs() * step.jitter;
+    let hue = jittered_hue(step, f);
+    let saturation = (math::cos(iteration * 0.35) / 5.0).abs();
+    let value = 0.5 + (math::cos(iteration) / 2.0).abs();
+    Hsv::new(hue, saturation, value)
+}
+
+fn palette_random(step: &Step) -> Hsv {
+    let iteration = step.iteration as f32;
+    let f = math::tan(iteration * 55.0).abs() * step.jitter;
+    let hue = jittered_hue(step, f);
+    let saturation = math::sin(iteration * 0.35).abs();
+    let value = math::cos((6.33 * iteration) * 0.5).abs();
+    Hsv::new(hue, saturation, value)
+}
+
+/// The Oklch hue `PaletteType::Oklch` walks to in `step`
+fn oklch_hue(step: &Step) -> Hue {
+    match step.walk {
+        HueWalk::Accumulate => (step.previous_hue + step.divergence) % 360.0,
+        HueWalk::Indexed => walk(step, step.iteration),
+    }
+}
+
+fn palette_oklch(step: &Step) -> Hsv {
+    let color = Oklch::new(OKLCH_LIGHTNESS, OKLCH_CHROMA, oklch_hue(step)).to_color_in_gamut();
+    color.to_hsv()
+}
+
+fn palette_harmony(step: &Step, harmony: Harmony) -> Hsv {
+    let offsets = harmony.offsets();
+    let rule_len = offsets.len();
+    let i = step.iteration;
+
+    let hue = match step.walk {
+        HueWalk::Accumulate => {
+            // Walk from the previous hue to the next hue of the rule
+            let mut delta = 0.0;
+            if i > 0 {
+                delta = offsets[i % rule_len] - offsets[(i - 1) % rule_len];
+                if i % (rule_len * HARMONY_VARIANTS.len()) == 0 {
+                    delta += HARMONY_ROTATION;
+                }
+            }
+            step.previous_hue + delta
+        }
+        HueWalk::Indexed => {
+            // The rule rotates once after going through all variants
+            let rotations = (i / (rule_len * HARMONY_VARIANTS.len())) as f64;
+            let rotation = math::rem_euclid_f64(rotations * HARMONY_ROTATION as f64, 360.0) as Hue;
+            step.start_hue + rotation + offsets[i % rule_len]
+        }
+    };
+
+    let (saturation, value) = HARMONY_VARIANTS[(i / rule_len) % HARMONY_VARIANTS.len()];
+    Hsv::new(hue, saturation, value)
+}
+
+fn palette_monochrome(step: &Step) -> Hsv {
+    let iteration = step.iteration as f32;
+    let saturation = 0.2 + 0.75 * math::fract(0.5 + MONOCHROME_STEPS.0 * iteration);
+    let value = 0.3 + 0.65 * math::fract(0.5 + MONOCHROME_STEPS.1 * iteration);
+    let hue = match step.walk {
+        HueWalk::Accumulate => step.previous_hue,
+        HueWalk::Indexed => step.start_hue,
+    };
+    Hsv::new(hue, saturation, value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PaletteStrategy, Step};
+    use crate::{Hsv, HsvPalette, Spread};
+
+    /// Cycles through a fixed list of brand colors, getting darker every round
+    struct Corporate {
+        colors: Vec<Hsv>,
+    }
+
+    impl PaletteStrategy for Corporate {
+        fn hsv(&mut self, step: Step) -> Hsv {
+            let color = self.colors[step.iteration % self.colors.len()];
+            let round = (step.iteration / self.colors.len()) as f32;
+            Hsv::new(color.hue(), color.saturation(), color.value() - 0.2 * round)
+        }
+    }
+
+    #[test]
+    fn custom_strategy() {
+        let colors = vec![Hsv::new(210.0, 0.8, 0.9), Hsv::new(30.0, 0.9, 0.9)];
+        let palette = HsvPalette::with_strategy(Corporate { colors: colors.clone() }, Spread::Wide, &mut rand::thread_rng());
+        let generated: Vec<_> = palette.take(4).collect();
+
+        assert_eq!(generated[..2], colors[..]);
+        assert_eq!(generated[2], Hsv::new(210.0, 0.8, 0.7));
+        assert_eq!(generated[3], Hsv::new(30.0, 0.9, 0.7));
+    }
+}